@@ -64,6 +64,13 @@ pub fn json_arg() -> Arg {
         .help("Output diagnostics as JSON")
 }
 
+pub fn graph_arg() -> Arg {
+    Arg::new("graph")
+        .long("graph")
+        .action(ArgAction::SetTrue)
+        .help("Show the optimized NFA as a Graphviz DOT graph instead of bytecode")
+}
+
 pub fn query_view_arg() -> Arg {
     Arg::new("query_view")
         .long("query-view")
@@ -92,7 +99,7 @@ pub fn format_arg() -> Arg {
         .long("format")
         .value_name("FORMAT")
         .default_value("typescript")
-        .help("Output format (typescript, ts)")
+        .help("Output format (typescript, ts, json-schema, python, py)")
 }
 
 pub fn include_points_arg() -> Arg {
@@ -165,6 +172,20 @@ pub fn compact_arg() -> Arg {
         .help("Output compact JSON (default: pretty when stdout is a TTY)")
 }
 
+pub fn positions_arg() -> Arg {
+    Arg::new("positions")
+        .long("positions")
+        .action(ArgAction::SetTrue)
+        .help("Output `path:line:col: line` for the result's first node instead of JSON")
+}
+
+pub fn parse_errors_arg() -> Arg {
+    Arg::new("parse_errors")
+        .long("parse-errors")
+        .action(ArgAction::SetTrue)
+        .help("Warn about ERROR and MISSING nodes in the source before matching")
+}
+
 pub fn entry_arg() -> Arg {
     Arg::new("entry")
         .long("entry")
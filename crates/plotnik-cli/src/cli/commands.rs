@@ -17,6 +17,8 @@ fn with_hidden_source_args(cmd: Command) -> Command {
 fn with_hidden_exec_args(cmd: Command) -> Command {
     cmd.arg(entry_arg().hide(true))
         .arg(compact_arg().hide(true))
+        .arg(positions_arg().hide(true))
+        .arg(parse_errors_arg().hide(true))
         .arg(include_points_arg().hide(true))
 }
 
@@ -24,6 +26,8 @@ fn with_hidden_exec_args(cmd: Command) -> Command {
 fn with_hidden_exec_args_partial(cmd: Command) -> Command {
     cmd.arg(entry_arg().hide(true))
         .arg(compact_arg().hide(true))
+        .arg(positions_arg().hide(true))
+        .arg(parse_errors_arg().hide(true))
 }
 
 fn with_hidden_trace_args(cmd: Command) -> Command {
@@ -190,12 +194,15 @@ pub fn dump_command() -> Command {
         .after_help(
             r#"EXAMPLES:
   plotnik dump query.ptk -l ts       # resolved node kinds
-  plotnik dump -q 'Q = ...' -l ts    # inline query"#,
+  plotnik dump -q 'Q = ...' -l ts    # inline query
+  plotnik dump query.ptk -l ts --graph | dot -Tsvg > nfa.svg"#,
         )
         .arg(query_path_arg())
         .next_help_heading("Input options")
         .arg(query_text_arg())
         .arg(lang_arg())
+        .next_help_heading("Output options")
+        .arg(graph_arg())
         .next_help_heading("Global options")
         .arg(color_arg());
 
@@ -251,7 +258,8 @@ pub fn run_command() -> Command {
             r#"EXAMPLES:
   plotnik run query.ptk app.js           # two positional files
   plotnik run -q 'Q = ...' app.js        # inline query + source file
-  plotnik run -q 'Q = ...' -s 'let x' -l js  # all inline"#,
+  plotnik run -q 'Q = ...' -s 'let x' -l js  # all inline
+  plotnik run query.ptk app.js --positions  # app.js:3:7: <line>"#,
         )
         .arg(query_path_arg())
         .arg(source_path_arg())
@@ -262,6 +270,8 @@ pub fn run_command() -> Command {
         .arg(entry_arg())
         .next_help_heading("Output options")
         .arg(compact_arg())
+        .arg(positions_arg())
+        .arg(parse_errors_arg())
         .arg(include_points_arg().hide(true))
         .next_help_heading("Limit options")
         .arg(fuel_arg())
@@ -307,6 +317,8 @@ pub fn trace_command() -> Command {
 
     with_hidden_json_arg(
         cmd.arg(compact_arg().hide(true))
+            .arg(positions_arg().hide(true))
+            .arg(parse_errors_arg().hide(true))
             .arg(include_points_arg().hide(true)),
     )
 }
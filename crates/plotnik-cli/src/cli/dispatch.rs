@@ -131,6 +131,7 @@ pub struct DumpOpts {
     pub query_path: Option<PathBuf>,
     pub query_text: Option<String>,
     pub lang: Option<String>,
+    pub graph: bool,
     pub color: ColorChoice,
     // Note: source_path, source_text, entry, compact, include_points, verbose,
     // no_result, and the runtime-limit flags are parsed but not extracted.
@@ -142,6 +143,7 @@ impl DumpOpts {
             query_path: m.get_one::<PathBuf>("query_path").cloned(),
             query_text: m.get_one::<String>("query_text").cloned(),
             lang: m.get_one::<String>("lang").cloned(),
+            graph: m.get_flag("graph"),
             color: ColorChoice::from_matches(m),
         }
     }
@@ -153,6 +155,7 @@ impl From<DumpOpts> for DumpArgs {
             query_path: p.query_path,
             query_text: p.query_text,
             lang: p.lang,
+            graph: p.graph,
             color: p.color.should_colorize(),
         }
     }
@@ -264,6 +267,8 @@ pub struct RunOpts {
     pub source_text: Option<String>,
     pub lang: Option<String>,
     pub compact: bool,
+    pub positions: bool,
+    pub parse_errors: bool,
     pub entry: Option<String>,
     pub limits: RuntimeLimitSpec,
     pub json: bool,
@@ -288,6 +293,8 @@ impl RunOpts {
             source_text: m.get_one::<String>("source_text").cloned(),
             lang: m.get_one::<String>("lang").cloned(),
             compact: m.get_flag("compact"),
+            positions: m.get_flag("positions"),
+            parse_errors: m.get_flag("parse_errors"),
             entry: m.get_one::<String>("entry").cloned(),
             limits: resolve_limit_spec(m),
             json: m.get_flag("json"),
@@ -307,6 +314,8 @@ impl From<RunOpts> for RunArgs {
             source_text: p.source_text,
             lang: p.lang,
             pretty,
+            positions: p.positions,
+            parse_errors: p.parse_errors,
             entry: p.entry,
             limits: p.limits,
             json: p.json,
@@ -77,6 +77,8 @@ fn shebang_parser() -> Command {
         .arg(strict_arg())
         .arg(json_arg())
         .arg(compact_arg())
+        .arg(positions_arg())
+        .arg(parse_errors_arg())
         .arg(include_points_arg())
         .arg(verbose_arg())
         .arg(no_result_arg())
@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use plotnik_lib::Colors;
 use plotnik_lib::bytecode::dump;
 
-use super::compile::compile_module;
+use super::compile::{compile_module, compile_query};
 use super::lang_resolver::require_lang;
 use super::query_loader::load_query;
 use crate::error::{CliError, CliResult, write_stdout};
@@ -12,6 +12,7 @@ pub struct DumpArgs {
     pub query_path: Option<PathBuf>,
     pub query_text: Option<String>,
     pub lang: Option<String>,
+    pub graph: bool,
     pub color: bool,
 }
 
@@ -24,6 +25,15 @@ pub fn run(args: DumpArgs) -> CliResult {
 
     let lang = require_lang(args.lang.as_deref(), loaded.shebang.lang.as_deref(), "dump")?;
 
+    if args.graph {
+        let compiled = compile_query(loaded.sources, lang, args.color)?;
+        let graph = compiled
+            .dump_nfa_dot()
+            .expect("error-free compilation retains its NFA");
+        write_stdout(format_args!("{graph}"))?;
+        return Ok(());
+    }
+
     let module = compile_module(loaded.sources, lang, args.color)?;
     let colors = Colors::new(args.color);
     write_stdout(format_args!("{}", dump(&module, colors)))?;
@@ -1,7 +1,10 @@
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use plotnik_lib::{TypeScriptCodegenConfig, TypeScriptMatchOnlyType};
+use plotnik_lib::{
+    CompiledQuery, Emission, JsonSchemaConfig, PythonCodegenConfig, TypeScriptCodegenConfig,
+    TypeScriptMatchOnlyType,
+};
 
 use super::compile::compile_query;
 use super::lang_resolver::require_lang;
@@ -21,11 +24,24 @@ pub struct InferArgs {
     pub match_only_type: Option<String>,
 }
 
+enum InferFormat {
+    TypeScript,
+    JsonSchema,
+    Python,
+}
+
 pub fn run(args: InferArgs) -> CliResult {
     let fmt = args.format.to_lowercase();
-    if fmt != "typescript" && fmt != "ts" {
-        return Err(CliError::fatal("--format must be 'typescript' or 'ts'"));
-    }
+    let format = match fmt.as_str() {
+        "typescript" | "ts" => InferFormat::TypeScript,
+        "json-schema" => InferFormat::JsonSchema,
+        "python" | "py" => InferFormat::Python,
+        _ => {
+            return Err(CliError::fatal(
+                "--format must be 'typescript', 'ts', 'json-schema', 'python', or 'py'",
+            ));
+        }
+    };
 
     let loaded = load_query(args.query_path.as_deref(), args.query_text.as_deref())?;
 
@@ -41,6 +57,22 @@ pub fn run(args: InferArgs) -> CliResult {
 
     let compiled = compile_query(loaded.sources, lang, args.color)?;
 
+    if let InferFormat::Python = format {
+        let emission = compiled
+            .emit_types(PythonCodegenConfig::new())
+            .map_err(|error| CliError::fatal(error.to_string()))?;
+        let output = emitted_artifact(emission, &compiled, args.color)?.into_source();
+        return write_output(&output, args.output.as_deref(), "Python types");
+    }
+
+    if let InferFormat::JsonSchema = format {
+        let emission = compiled
+            .emit_types(JsonSchemaConfig::new())
+            .map_err(|error| CliError::fatal(error.to_string()))?;
+        let output = emitted_artifact(emission, &compiled, args.color)?.to_pretty_string();
+        return write_output(&output, args.output.as_deref(), "schema");
+    }
+
     let match_only_type = match args.match_only_type.as_deref() {
         Some("null") => TypeScriptMatchOnlyType::Null,
         _ => TypeScriptMatchOnlyType::Undefined,
@@ -56,38 +88,42 @@ pub fn run(args: InferArgs) -> CliResult {
     let emission = compiled
         .emit_types(config)
         .map_err(|error| CliError::fatal(error.to_string()))?;
-    let has_errors = emission.diagnostics().has_errors();
+    let output = emitted_artifact(emission, &compiled, args.color)?
+        .into_parts()
+        .0;
+
+    let summary = format!("{} types", count_types(&output));
+    write_output(&output, args.output.as_deref(), &summary)
+}
+
+/// Report an emission's diagnostics and hand back its artifact, or fail when
+/// the target rejected the query.
+fn emitted_artifact<T>(
+    emission: Emission<T>,
+    compiled: &CompiledQuery,
+    color: bool,
+) -> Result<T, CliError> {
     if !emission.diagnostics().is_empty() {
         write_stderr(format_args!(
             "{}",
             emission
                 .diagnostics()
-                .render_colored(compiled.source_map(), args.color)
+                .render_colored(compiled.source_map(), color)
         ))?;
     }
-    if has_errors {
+    if emission.diagnostics().has_errors() {
         return Err(CliError::No);
     }
-    let output = emission
-        .into_artifact()
-        .expect("valid query emits TypeScript types")
-        .into_parts()
-        .0;
-
-    if let Some(ref path) = args.output {
-        fs::write(path, &output)
-            .map_err(|e| CliError::fatal(format!("failed to write '{}': {}", path.display(), e)))?;
-        let type_count = count_types(&output);
-        writeln_stderr(format_args!(
-            "Wrote {} types to {}",
-            type_count,
-            path.display()
-        ))?;
-    } else {
-        write_stdout(format_args!("{output}"))?;
-    }
+    emission.into_artifact().ok_or(CliError::No)
+}
 
-    Ok(())
+fn write_output(output: &str, path: Option<&Path>, summary: &str) -> CliResult {
+    let Some(path) = path else {
+        return write_stdout(format_args!("{output}"));
+    };
+    fs::write(path, output)
+        .map_err(|e| CliError::fatal(format!("failed to write '{}': {}", path.display(), e)))?;
+    writeln_stderr(format_args!("Wrote {} to {}", summary, path.display()))
 }
 
 fn count_types(output: &str) -> usize {
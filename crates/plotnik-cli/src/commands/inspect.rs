@@ -103,7 +103,7 @@ pub fn run(args: InspectArgs) -> CliResult {
     let entry_points = module.as_ref().map(entry_point_names).unwrap_or_default();
 
     let run = if let Some(module) = module.as_ref() {
-        let default_entry = module.entry_point_names().last().map(str::to_owned);
+        let default_entry = module.default_entry_point_name().map(str::to_owned);
         let entry = args.entry.clone().or(shebang_entry).or(default_entry);
         let entry_point = run_common::resolve_entry_point(module, entry.as_deref())?;
         let tree = lang.parse_source(&source_code);
@@ -12,6 +12,11 @@ pub mod run_common;
 pub mod runtime_report;
 pub mod trace;
 pub mod tree;
+pub mod tree_errors;
 
 #[cfg(test)]
 mod lang_tests;
+#[cfg(test)]
+mod run_tests;
+#[cfg(test)]
+mod tree_errors_tests;
@@ -3,12 +3,13 @@
 use std::path::PathBuf;
 
 use plotnik_lib::{
-    Colors, NoopTracer, RuntimeError, RuntimeLimitSpec, VM, extract_result_provenance,
-    materialize_verified,
+    Colors, NodeValue, NoopTracer, RuntimeError, RuntimeLimitSpec, VM, Value,
+    extract_result_provenance, materialize_verified,
 };
 
 use super::run_common::{self, ExecPlan, ExecRequest};
 use super::runtime_report::render_runtime_error;
+use super::tree_errors::{TreeError, TreeErrorKind, collect_tree_errors};
 use crate::error::{CliError, CliResult, writeln_stderr, writeln_stdout};
 
 pub struct RunArgs {
@@ -18,6 +19,8 @@ pub struct RunArgs {
     pub source_text: Option<String>,
     pub lang: Option<String>,
     pub pretty: bool,
+    pub positions: bool,
+    pub parse_errors: bool,
     pub entry: Option<String>,
     pub limits: RuntimeLimitSpec,
     pub json: bool,
@@ -41,6 +44,17 @@ pub fn run(args: RunArgs) -> CliResult {
         inspection: args.json,
     })?;
 
+    if args.parse_errors {
+        let errors = collect_tree_errors(tree.root_node());
+        if !errors.is_empty() {
+            let path = source_display_path(&args);
+            writeln_stderr(format_args!(
+                "{}",
+                format_tree_errors(&path, &source_code, &errors)
+            ))?;
+        }
+    }
+
     let vm = VM::builder(&source_code, &tree).limits(args.limits).build();
     if args.json {
         let mut tracer = NoopTracer;
@@ -107,8 +121,93 @@ pub fn run(args: RunArgs) -> CliResult {
         colors,
     );
 
+    if args.positions {
+        let path = source_display_path(&args);
+        let Some(position) = format_position(&path, &source_code, &value) else {
+            writeln_stderr(format_args!("result has no node to locate"))?;
+            return Err(CliError::FatalRendered);
+        };
+        writeln_stdout(format_args!("{position}"))?;
+        return Ok(());
+    }
+
     let output = value.format(args.pretty, colors);
     writeln_stdout(format_args!("{output}"))?;
 
     Ok(())
 }
+
+/// How positions name the source: its path, or a placeholder for stdin and
+/// inline text.
+fn source_display_path(args: &RunArgs) -> String {
+    match &args.source_path {
+        Some(path) if path.as_os_str() != "-" => path.display().to_string(),
+        Some(_) => "<stdin>".to_string(),
+        None => "<source>".to_string(),
+    }
+}
+
+/// Grep-style `path:line:col: line` for the earliest node in the result, with a
+/// one-based line and one-based byte column. `None` when the result holds no node.
+pub(crate) fn format_position(path: &str, source: &str, value: &Value<'_>) -> Option<String> {
+    let start = first_node(value)?.span.0 as usize;
+    let (line, column, line_start) = line_column(source, start);
+    let line_end = source[start..]
+        .find('\n')
+        .map_or(source.len(), |i| start + i);
+    let text = source[line_start..line_end].trim_end_matches('\r');
+    Some(format!("{path}:{line}:{column}: {text}"))
+}
+
+/// One `path:line:col: warning: ...` line per recovery point tree-sitter left
+/// in the source, followed by a count. Matches near these points may be
+/// incomplete, since the query ran over the recovered tree.
+pub(crate) fn format_tree_errors(path: &str, source: &str, errors: &[TreeError]) -> String {
+    let mut out = String::new();
+    for error in errors {
+        let (line, column, _) = line_column(source, error.span.0 as usize);
+        let what = match error.kind {
+            TreeErrorKind::Error => "syntax error".to_string(),
+            TreeErrorKind::Missing => format!("missing `{}`", error.node_kind),
+        };
+        out.push_str(&format!("{path}:{line}:{column}: warning: {what}\n"));
+    }
+    let plural = if errors.len() == 1 { "" } else { "s" };
+    out.push_str(&format!(
+        "warning: source has {} parse error{plural}, results may be incomplete",
+        errors.len()
+    ));
+    out
+}
+
+/// One-based line and one-based byte column of `offset`, plus the byte offset
+/// where its line starts.
+fn line_column(source: &str, offset: usize) -> (usize, usize, usize) {
+    let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line = source[..offset].matches('\n').count() + 1;
+    (line, offset - line_start + 1, line_start)
+}
+
+/// The node that starts first in the source. Walks with an explicit stack:
+/// results of captured-recursive queries nest as deep as the match.
+fn first_node<'a>(value: &'a Value<'a>) -> Option<&'a NodeValue<'a>> {
+    let mut first: Option<&NodeValue<'_>> = None;
+    let mut stack = vec![value];
+    while let Some(value) = stack.pop() {
+        match value {
+            Value::Node(node) => {
+                if first.is_none_or(|first| node.span.0 < first.span.0) {
+                    first = Some(node);
+                }
+            }
+            Value::List(items) => stack.extend(items),
+            Value::Record(fields) => stack.extend(fields.iter().map(|(_, value)| value)),
+            Value::Variant {
+                payload: Some(payload),
+                ..
+            } => stack.push(payload),
+            Value::Absent | Value::Text(_) | Value::Bool(_) | Value::Variant { .. } => {}
+        }
+    }
+    first
+}
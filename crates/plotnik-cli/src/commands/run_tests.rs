@@ -0,0 +1,75 @@
+use plotnik_lib::{NodeValue, Value};
+
+use super::run::{format_position, format_tree_errors};
+use super::tree_errors::{TreeError, TreeErrorKind};
+
+const SOURCE: &str = "let a = 1;\r\nfunction go() {\n  return a;\n}\n";
+
+fn node(kind: &'static str, text: &'static str) -> Value<'static> {
+    let start = SOURCE.find(text).expect("text occurs in the source") as u32;
+    Value::Node(NodeValue {
+        kind,
+        text,
+        span: (start, start + text.len() as u32),
+    })
+}
+
+#[test]
+fn position_points_at_the_earliest_node() {
+    // Field order differs from source order: `names` holds the earliest node.
+    let value = Value::Record(vec![
+        ("ret", node("return_statement", "return a;")),
+        (
+            "names",
+            Value::List(vec![node("identifier", "go"), node("identifier", "a")]),
+        ),
+    ]);
+
+    assert_eq!(
+        format_position("app.js", SOURCE, &value).as_deref(),
+        Some("app.js:1:5: let a = 1;")
+    );
+}
+
+#[test]
+fn position_counts_columns_within_the_line() {
+    let value = Value::Variant {
+        case: "Return",
+        payload: Some(Box::new(node("return_statement", "return a;"))),
+    };
+
+    assert_eq!(
+        format_position("app.js", SOURCE, &value).as_deref(),
+        Some("app.js:3:3:   return a;")
+    );
+}
+
+#[test]
+fn position_needs_a_node() {
+    let value = Value::Record(vec![("name", Value::Text("go"))]);
+
+    assert_eq!(format_position("app.js", SOURCE, &value), None);
+}
+
+#[test]
+fn tree_errors_are_located_and_counted() {
+    let errors = [
+        TreeError {
+            kind: TreeErrorKind::Error,
+            node_kind: "ERROR",
+            span: (6, 7),
+        },
+        TreeError {
+            kind: TreeErrorKind::Missing,
+            node_kind: "}",
+            span: (40, 40),
+        },
+    ];
+
+    assert_eq!(
+        format_tree_errors("app.js", SOURCE, &errors),
+        "app.js:1:7: warning: syntax error\n\
+         app.js:4:1: warning: missing `}`\n\
+         warning: source has 2 parse errors, results may be incomplete"
+    );
+}
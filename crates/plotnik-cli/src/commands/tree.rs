@@ -2,7 +2,7 @@
 
 use std::path::PathBuf;
 
-use plotnik_lib::{QueryBuilder, dump_tree_text, tree_to_json};
+use plotnik_lib::{Query, QueryBuilder, dump_tree_text, tree_to_json};
 use serde_json::{Map, Value, json};
 
 use super::lang_resolver::reconcile_lang;
@@ -82,6 +82,25 @@ fn print_query_tree(args: &TreeArgs) -> Result<Option<String>, CliError> {
 }
 
 fn render_query_tree(args: &TreeArgs) -> Result<(String, Option<String>), CliError> {
+    let (query, declared_lang) = analyze_query(args)?;
+    let output = match args.query_view {
+        QueryView::Ast => query.dump_ast(),
+        QueryView::Cst => query.dump_cst_with_trivia(true),
+    };
+    Ok((output, declared_lang))
+}
+
+/// The AST view is structured JSON; the CST view stays its text dump.
+fn query_tree_json(args: &TreeArgs) -> Result<(Value, Option<String>), CliError> {
+    let (query, declared_lang) = analyze_query(args)?;
+    let output = match args.query_view {
+        QueryView::Ast => query.ast_json(),
+        QueryView::Cst => json!(query.dump_cst_with_trivia(true)),
+    };
+    Ok((output, declared_lang))
+}
+
+fn analyze_query(args: &TreeArgs) -> Result<(Query, Option<String>), CliError> {
     let loaded = load_query(args.query_path.as_deref(), args.query_text.as_deref())?;
 
     if loaded.sources.is_empty() {
@@ -104,11 +123,7 @@ fn render_query_tree(args: &TreeArgs) -> Result<(String, Option<String>), CliErr
         ))?;
     }
 
-    let output = match args.query_view {
-        QueryView::Ast => query.dump_ast(),
-        QueryView::Cst => query.dump_cst_with_trivia(true),
-    };
-    Ok((output, loaded.shebang.lang))
+    Ok((query, loaded.shebang.lang))
 }
 
 fn source_tree_json(args: &TreeArgs, declared_lang: Option<&str>) -> Result<Value, CliError> {
@@ -152,8 +167,8 @@ fn print_json(args: &TreeArgs, has_query: bool, has_source: bool) -> CliResult {
     let mut declared_lang = None;
 
     if has_query {
-        let (query_tree, lang) = render_query_tree(args)?;
-        output.insert("query_tree".to_string(), json!(query_tree));
+        let (query_tree, lang) = query_tree_json(args)?;
+        output.insert("query_tree".to_string(), query_tree);
         declared_lang = lang;
     }
     if has_source {
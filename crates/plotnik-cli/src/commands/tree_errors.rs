@@ -0,0 +1,62 @@
+//! Parse errors tree-sitter recovered from in the source tree.
+//!
+//! Tree-sitter always produces a tree: unparseable text becomes an `ERROR`
+//! node and a token the parser had to invent becomes a zero-width `MISSING`
+//! node. Queries run over the recovered tree as-is, so results near these
+//! nodes may not reflect what the author meant.
+
+use tree_sitter::Node;
+
+/// How tree-sitter recovered at a [`TreeError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeErrorKind {
+    /// Text the parser skipped, wrapped in an `ERROR` node.
+    Error,
+    /// A token the parser assumed but the source lacks.
+    Missing,
+}
+
+/// One recovery point in the source tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeError {
+    pub kind: TreeErrorKind,
+    /// `ERROR` for skipped text, the expected kind for a missing token.
+    pub node_kind: &'static str,
+    /// Half-open document byte range `[start, end)`; empty for missing tokens.
+    pub span: (u32, u32),
+}
+
+/// Every `ERROR` and `MISSING` node under `root`, in document order.
+///
+/// Only subtrees that report an error are entered, and an `ERROR` node is
+/// reported once rather than once per error nested inside it.
+pub fn collect_tree_errors(root: Node<'_>) -> Vec<TreeError> {
+    let mut errors = Vec::new();
+    let mut cursor = root.walk();
+    loop {
+        let node = cursor.node();
+        let kind = if node.is_error() {
+            Some(TreeErrorKind::Error)
+        } else if node.is_missing() {
+            Some(TreeErrorKind::Missing)
+        } else {
+            None
+        };
+        if let Some(kind) = kind {
+            errors.push(TreeError {
+                kind,
+                node_kind: node.kind(),
+                span: (node.start_byte() as u32, node.end_byte() as u32),
+            });
+        } else if node.has_error() && cursor.goto_first_child() {
+            continue;
+        }
+
+        // The cursor's root has no siblings, so climbing back to it ends the walk.
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                return errors;
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "lang-javascript")]
+
+use super::tree_errors::{TreeErrorKind, collect_tree_errors};
+use crate::language_registry;
+
+#[test]
+fn clean_source_has_no_tree_errors() {
+    let tree = language_registry::javascript().parse_source("let a = 1;\nfoo(a);\n");
+
+    assert!(collect_tree_errors(tree.root_node()).is_empty());
+}
+
+#[test]
+fn broken_source_reports_where_recovery_happened() {
+    let source = "let a = ;\nlet b = 2;\n";
+    let tree = language_registry::javascript().parse_source(source);
+    assert!(tree.root_node().has_error());
+
+    let errors = collect_tree_errors(tree.root_node());
+
+    assert!(!errors.is_empty());
+    let first_line_end = source.find('\n').expect("source has a newline") as u32;
+    for error in &errors {
+        assert!(error.span.0 <= first_line_end, "{error:?}");
+        match error.kind {
+            TreeErrorKind::Error => assert_eq!(error.node_kind, "ERROR"),
+            TreeErrorKind::Missing => assert_eq!(error.span.0, error.span.1),
+        }
+    }
+    assert!(
+        errors
+            .windows(2)
+            .all(|pair| pair[0].span.0 <= pair[1].span.0)
+    );
+}
+
+#[test]
+fn errors_in_separate_statements_are_reported_separately() {
+    let tree = language_registry::javascript().parse_source("let a = ;\nok();\nlet b = ;\n");
+
+    let errors = collect_tree_errors(tree.root_node());
+
+    assert!(errors.iter().any(|error| error.span.0 < 9));
+    assert!(errors.iter().any(|error| error.span.0 >= 15));
+}
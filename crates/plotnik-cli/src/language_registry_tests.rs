@@ -0,0 +1,26 @@
+#![cfg(all(feature = "lang-javascript", feature = "lang-go"))]
+
+use plotnik_lib::Query;
+use plotnik_lib::diagnostics::DiagnosticKind;
+
+use crate::language_registry;
+
+/// One analysis links against each language on its own: JavaScript's
+/// `for_statement` has an `increment` field, Go's keeps it on `for_clause`.
+#[test]
+fn one_query_links_per_language() {
+    let query = Query::try_from("Q = (for_statement increment: (_) @step)").unwrap();
+
+    let javascript = query.link(language_registry::javascript().grammar());
+    let go = query.link(language_registry::go().grammar());
+
+    let javascript = javascript.expect("JavaScript has the field");
+    assert!(javascript.is_valid());
+    let Err(diagnostics) = go else {
+        panic!("Go's for_statement has no `increment` field");
+    };
+    assert_eq!(
+        diagnostics.kinds().collect::<Vec<_>>(),
+        [DiagnosticKind::UnknownGrammarField]
+    );
+}
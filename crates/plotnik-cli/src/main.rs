@@ -3,6 +3,9 @@ mod commands;
 mod error;
 mod language_registry;
 
+#[cfg(test)]
+mod language_registry_tests;
+
 use std::io::{self, Write as _};
 use std::process::ExitCode;
 
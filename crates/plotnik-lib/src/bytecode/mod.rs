@@ -27,15 +27,20 @@ mod predicate_op;
 mod render;
 mod sections;
 mod spans;
+mod type_description;
 mod type_meta;
 pub mod type_system;
 
+#[cfg(test)]
+mod type_description_tests;
+
 pub use dump::dump;
 pub use entry_point::EntryPoint;
 pub use ids::{StringId, TypeId};
 pub use instructions::{CodeAddr, EncodeError};
 pub use module::{EntryPointsView, Module, StringsView, TypesView};
 pub use spans::{Labeling, SPAN_NO_BINDING, SpanEntry, SpanKind, SpansView};
+pub use type_description::TypeDescription;
 pub use type_meta::{TypeDef, TypeDefKind, TypeMember, TypeNameEntry};
 pub use type_system::{PrimitiveType, TypeKind};
 
@@ -0,0 +1,27 @@
+use crate::compiler::test_utils::synthetic_grammar as grammar;
+use crate::compiler::{BytecodeConfig, QueryBuilder};
+
+use super::Module;
+
+fn module(query: &str) -> Module {
+    QueryBuilder::from_inline(query)
+        .compile(grammar())
+        .expect("query compiles")
+        .emit(BytecodeConfig::new())
+        .expect("bytecode emission answers")
+        .into_artifact()
+        .expect("query emits a module")
+}
+
+#[test]
+fn last_selectable_definition_is_the_default() {
+    let module = module(
+        "Item = (identifier) @name\n\
+         Decls = (program (lexical_declaration)* @decls)\n\
+         Calls = (program (expression_statement)* @calls)\n",
+    );
+
+    let name = module.default_entry_point_name();
+
+    assert_eq!(name, Some("Calls"));
+}
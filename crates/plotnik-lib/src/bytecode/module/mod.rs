@@ -256,6 +256,14 @@ impl Module {
         self.entry_points().find_by_name(name, &self.strings())
     }
 
+    /// The entry point a run uses when the caller names none: the last
+    /// selectable definition, since queries conventionally put the preferred
+    /// one last. The table keeps declaration order; `None` only when the
+    /// module exports no entry points.
+    pub fn default_entry_point_name(&self) -> Option<&str> {
+        self.entry_point_names().last()
+    }
+
     /// Names of all entry points, in table order.
     pub fn entry_point_names(&self) -> impl Iterator<Item = &str> {
         let strings = self.strings();
@@ -310,6 +318,18 @@ impl<'a> StringsView<'a> {
         let end = read_u32_le(self.table, (idx + 1) * STRING_TABLE_ENTRY_SIZE) as usize;
         std::str::from_utf8(&self.blob[start..end]).expect("invalid UTF-8 in string table")
     }
+
+    /// Every interned string with its id, in table order.
+    ///
+    /// Skips the reserved entry at index 0, which no `StringId` can name;
+    /// read it with [`at(0)`](Self::at) when a dump needs it.
+    pub fn iter(&self) -> impl Iterator<Item = (StringId, &'a str)> + '_ {
+        let count = self.table.len() / STRING_TABLE_ENTRY_SIZE - 1;
+        (1..count).map(|idx| {
+            let id = StringId::try_from(idx as u16).expect("index past 0 is a valid StringId");
+            (id, self.at(idx))
+        })
+    }
 }
 
 pub struct NodeKindTableView<'a> {
@@ -541,5 +561,9 @@ impl<'a> EntryPointsView<'a> {
     }
 }
 
+#[cfg(test)]
+mod entry_points_tests;
+#[cfg(test)]
+mod strings_tests;
 #[cfg(test)]
 mod validate_tests;
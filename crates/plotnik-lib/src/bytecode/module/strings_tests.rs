@@ -0,0 +1,40 @@
+use crate::compiler::test_utils::synthetic_grammar as grammar;
+use crate::compiler::{BytecodeConfig, QueryBuilder};
+
+use super::Module;
+
+fn module(query: &str) -> Module {
+    QueryBuilder::from_inline(query)
+        .compile(grammar())
+        .expect("query compiles")
+        .emit(BytecodeConfig::new())
+        .expect("bytecode emission answers")
+        .into_artifact()
+        .expect("query emits a module")
+}
+
+#[test]
+fn iter_yields_interned_capture_names_with_their_ids() {
+    let module = module("Decl = (program (lexical_declaration) @decl)");
+    let strings = module.strings();
+
+    let (id, text) = strings
+        .iter()
+        .find(|(_, text)| *text == "decl")
+        .expect("capture name is interned");
+
+    assert_eq!(text, "decl");
+    assert_eq!(strings.get(id), "decl");
+}
+
+#[test]
+fn iter_skips_the_reserved_entry() {
+    let module = module("Decl = (program (lexical_declaration) @decl)");
+    let strings = module.strings();
+
+    assert!(strings.iter().all(|(_, text)| text != strings.at(0)));
+    assert_eq!(
+        strings.iter().count(),
+        module.header().str_table_count as usize - 1
+    );
+}
@@ -0,0 +1,166 @@
+//! Structured result types read back from a module's type table.
+//!
+//! Tools that list what a module can run (an entry-point menu, a REPL prompt)
+//! need each entry point's result shape without compiling the query again.
+
+use std::collections::{HashMap, HashSet};
+
+use super::type_meta::{TypeDef, TypeDefKind};
+use super::type_system::TypeKind;
+use super::{Module, StringsView, TypeId, TypesView};
+
+/// A module type resolved through the type table.
+///
+/// Records and variants carry their name from the type-name table when they
+/// have one. A named type is expanded once per entry point; reaching it again,
+/// whether through recursion or from a sibling field, yields a
+/// [`TypeDescription::Ref`] to that name.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TypeDescription {
+    NoValue,
+    Node,
+    Text,
+    Bool,
+    Optional {
+        inner: Box<TypeDescription>,
+    },
+    List {
+        element: Box<TypeDescription>,
+        non_empty: bool,
+    },
+    Record {
+        name: Option<String>,
+        fields: Vec<(String, TypeDescription)>,
+    },
+    Variant {
+        name: Option<String>,
+        cases: Vec<(String, TypeDescription)>,
+    },
+    Ref {
+        name: String,
+    },
+}
+
+impl Module {
+    /// Every entry point's name and result type, in table order.
+    pub fn entry_point_types(&self) -> Vec<(String, TypeDescription)> {
+        let strings = self.strings();
+        let types = self.types();
+        let names: HashMap<TypeId, &str> = types
+            .names()
+            .map(|entry| (entry.type_id, strings.get(entry.name_id)))
+            .collect();
+        let mut resolver = TypeResolver {
+            strings: self.strings(),
+            types,
+            names,
+            expanded: HashSet::new(),
+            expanding: Vec::new(),
+        };
+        self.entry_points()
+            .iter()
+            .map(|entry| {
+                let name = strings.get(entry.name()).to_string();
+                resolver.expanded.clear();
+                (name, resolver.describe(entry.result_type(), None))
+            })
+            .collect()
+    }
+}
+
+struct TypeResolver<'m> {
+    strings: StringsView<'m>,
+    types: TypesView<'m>,
+    names: HashMap<TypeId, &'m str>,
+    /// Named types already expanded for the current entry point.
+    expanded: HashSet<TypeId>,
+    /// Unnamed types on the current expansion path, to cut cycles through them.
+    expanding: Vec<TypeId>,
+}
+
+impl TypeResolver<'_> {
+    /// `alias_name` is the name of an alias that led here, used when the
+    /// target itself is unnamed.
+    fn describe(&mut self, type_id: TypeId, alias_name: Option<&str>) -> TypeDescription {
+        let name = self.names.get(&type_id).copied().or(alias_name);
+        if let Some(name) = name
+            && !self.expanded.insert(type_id)
+        {
+            let name = name.to_string();
+            return TypeDescription::Ref { name };
+        }
+        if self.expanding.contains(&type_id) {
+            let name = format!("T{type_id}");
+            return TypeDescription::Ref { name };
+        }
+        let def = self
+            .types
+            .get(type_id)
+            .expect("validated module type ids are in bounds");
+
+        self.expanding.push(type_id);
+        let description = match def.decode() {
+            TypeDefKind::Primitive(TypeKind::NoValue) => TypeDescription::NoValue,
+            TypeDefKind::Primitive(TypeKind::Node) => TypeDescription::Node,
+            TypeDefKind::Primitive(TypeKind::Text) => TypeDescription::Text,
+            TypeDefKind::Primitive(TypeKind::Bool) => TypeDescription::Bool,
+            TypeDefKind::Wrapper {
+                kind: TypeKind::Alias,
+                inner,
+            } => self.describe(inner, name),
+            TypeDefKind::Wrapper {
+                kind: TypeKind::Option,
+                inner,
+            } => TypeDescription::Optional {
+                inner: Box::new(self.describe(inner, None)),
+            },
+            TypeDefKind::Wrapper {
+                kind: kind @ (TypeKind::ListZeroOrMore | TypeKind::ListOneOrMore),
+                inner,
+            } => TypeDescription::List {
+                element: Box::new(self.describe(inner, None)),
+                non_empty: kind == TypeKind::ListOneOrMore,
+            },
+            TypeDefKind::Record { .. } => TypeDescription::Record {
+                name: name.map(str::to_string),
+                fields: self.members(&def),
+            },
+            TypeDefKind::Variant { .. } => TypeDescription::Variant {
+                name: name.map(str::to_string),
+                cases: self.members(&def),
+            },
+            TypeDefKind::Primitive(
+                kind @ (TypeKind::Option
+                | TypeKind::ListZeroOrMore
+                | TypeKind::ListOneOrMore
+                | TypeKind::Record
+                | TypeKind::Variant
+                | TypeKind::Alias),
+            )
+            | TypeDefKind::Wrapper {
+                kind:
+                    kind @ (TypeKind::NoValue
+                    | TypeKind::Node
+                    | TypeKind::Text
+                    | TypeKind::Bool
+                    | TypeKind::Record
+                    | TypeKind::Variant),
+                ..
+            } => unreachable!("decode never yields {kind:?} in this shape"),
+        };
+        self.expanding.pop();
+        description
+    }
+
+    fn members(&mut self, def: &TypeDef) -> Vec<(String, TypeDescription)> {
+        let members: Vec<_> = self.types.members_of(def).collect();
+        members
+            .into_iter()
+            .map(|member| {
+                let name = self.strings.get(member.name_id).to_string();
+                (name, self.describe(member.type_id, None))
+            })
+            .collect()
+    }
+}
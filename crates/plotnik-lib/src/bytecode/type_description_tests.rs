@@ -0,0 +1,92 @@
+use crate::compiler::test_utils::synthetic_grammar as grammar;
+use crate::compiler::{BytecodeConfig, QueryBuilder};
+
+use super::{Module, TypeDescription};
+
+fn module(query: &str) -> Module {
+    QueryBuilder::from_inline(query)
+        .compile(grammar())
+        .expect("query compiles")
+        .emit(BytecodeConfig::new())
+        .expect("bytecode emission answers")
+        .into_artifact()
+        .expect("query emits a module")
+}
+
+fn record(name: &str, fields: Vec<(&str, TypeDescription)>) -> TypeDescription {
+    TypeDescription::Record {
+        name: Some(name.to_string()),
+        fields: fields
+            .into_iter()
+            .map(|(field, ty)| (field.to_string(), ty))
+            .collect(),
+    }
+}
+
+#[test]
+fn entry_point_types_resolve_through_the_type_table() {
+    let module = module(
+        "Decl = (program (lexical_declaration) @decl)\n\
+         Fns = (program (function_declaration)+ @fns)\n",
+    );
+
+    let mut entries = module.entry_point_types();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    assert_eq!(
+        entries,
+        [
+            (
+                "Decl".to_string(),
+                record("Decl", vec![("decl", TypeDescription::Node)]),
+            ),
+            (
+                "Fns".to_string(),
+                record(
+                    "Fns",
+                    vec![(
+                        "fns",
+                        TypeDescription::List {
+                            element: Box::new(TypeDescription::Node),
+                            non_empty: true,
+                        },
+                    )],
+                ),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn named_type_expands_once_per_entry_point() {
+    let module = module(
+        "Stmt = (expression_statement (identifier) @id)\n\
+         Pair = (program (Stmt) @first (Stmt) @second)\n",
+    );
+    let stmt = || record("Stmt", vec![("id", TypeDescription::Node)]);
+
+    let mut entries = module.entry_point_types();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    assert_eq!(
+        entries,
+        [
+            (
+                "Pair".to_string(),
+                record(
+                    "Pair",
+                    vec![
+                        ("first", stmt()),
+                        (
+                            "second",
+                            TypeDescription::Ref {
+                                name: "Stmt".to_string(),
+                            },
+                        ),
+                    ],
+                ),
+            ),
+            ("Stmt".to_string(), stmt()),
+        ]
+    );
+}
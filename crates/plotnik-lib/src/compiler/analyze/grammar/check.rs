@@ -165,6 +165,9 @@ impl<'a, 'q> GrammarBinder<'a, 'q> {
                 self.check_pattern_grammar(&inner_located, ctx, participation, walk);
             }
             Pattern::QuantifiedPattern(q) => {
+                if let Some(ctx) = ctx {
+                    self.check_repeated_single_field(located, q, ctx);
+                }
                 let inner = q
                     .inner()
                     .expect("validated quantified pattern has an inner pattern");
@@ -415,6 +418,60 @@ impl<'a, 'q> GrammarBinder<'a, 'q> {
             .emit();
     }
 
+    /// Warn when `*` or `+` repeats a grammar field that holds at most one child.
+    /// The repetition can only ever collect one element, which usually means the
+    /// author expected a list field. A warning, not an error: grammars sometimes
+    /// under-declare `multiple`.
+    fn check_repeated_single_field(
+        &mut self,
+        located: &Located<Pattern>,
+        quantifier: &ast::QuantifiedPattern,
+        ctx: ParentNode,
+    ) {
+        if !quantifier.is_repeating() {
+            return;
+        }
+        let Some(Pattern::FieldPattern(field)) = quantifier.inner() else {
+            return;
+        };
+        let Some(name_token) = field.name() else {
+            return;
+        };
+        let field_name = name_token.text();
+        let Some(field_id) = self.node_field_ids.get(field_name).copied().flatten() else {
+            return;
+        };
+        if self
+            .grammar
+            .field_cardinality(ctx.id(), field_id)
+            .is_none_or(|cardinality| cardinality.is_multiple())
+        {
+            return;
+        }
+        let Some(operator) = quantifier.operator() else {
+            return;
+        };
+
+        let op = operator.text();
+        let parent_name = ctx.name(self.grammar);
+        let hint = if quantifier.is_optional() {
+            format!("replace `{op}` with `?`")
+        } else {
+            format!("drop `{op}`")
+        };
+        self.diag
+            .report(
+                DiagnosticKind::RepeatedSingleField,
+                located.span_of(operator.text_range()),
+            )
+            .detail(format!(
+                "`{op}` repeats `{field_name}`, but a `{parent_name}` has at most one `{field_name}`"
+            ))
+            .related_to(ctx.span(), format!("`{parent_name}` starts here"))
+            .hint(hint)
+            .emit();
+    }
+
     /// Whether a concrete child kind can occupy a bare child position whose parent admits
     /// `adm`. The parent is already known to be a non-leaf here.
     fn admissible_child(&self, child: NodeKindId, adm: &HashSet<NodeKindId>) -> bool {
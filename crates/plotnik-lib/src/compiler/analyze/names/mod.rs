@@ -3,5 +3,8 @@
 mod collected_definitions;
 mod resolve;
 
+#[cfg(test)]
+mod resolve_tests;
+
 pub(in crate::compiler::analyze) use collected_definitions::CollectedDefinitions;
 pub(in crate::compiler) use resolve::resolve_names;
@@ -6,7 +6,7 @@
 
 use crate::compiler::analyze::Located;
 use crate::compiler::analyze::shape::validation::ValidatedAst;
-use crate::compiler::analyze::visitor::Visitor;
+use crate::compiler::analyze::visitor::{Visitor, walk_def};
 use crate::compiler::diagnostics::report::{DiagnosticKind, Diagnostics};
 use crate::compiler::parse::ast::{self, token_src};
 use crate::core::utils::find_similar;
@@ -22,6 +22,7 @@ pub(in crate::compiler) fn resolve_names(
     for (&source_id, ast) in validated.ast_map() {
         let src = validated.source_map().content(source_id);
         let mut resolver = DefCollector {
+            validated,
             src,
             diag: &mut *diag,
             definitions: &mut definitions,
@@ -31,6 +32,7 @@ pub(in crate::compiler) fn resolve_names(
 
     for (&source_id, ast) in validated.ast_map() {
         let mut validator = ReferenceValidator {
+            validated,
             diag: &mut *diag,
             definitions: &definitions,
         };
@@ -41,6 +43,7 @@ pub(in crate::compiler) fn resolve_names(
 }
 
 struct DefCollector<'q, 'd, 'a> {
+    validated: &'a ValidatedAst<'q>,
     src: &'q str,
     diag: &'d mut Diagnostics,
     definitions: &'a mut CollectedDefinitions,
@@ -48,6 +51,9 @@ struct DefCollector<'q, 'd, 'a> {
 
 impl Visitor for DefCollector<'_, '_, '_> {
     fn visit_def(&mut self, def: &Located<ast::Def>) {
+        if !self.validated.admits(def.source(), def.node()) {
+            return;
+        }
         let Some(body) = def.node().body() else {
             return;
         };
@@ -73,19 +79,26 @@ impl Visitor for DefCollector<'_, '_, '_> {
     }
 }
 
-struct ReferenceValidator<'d, 'a> {
+struct ReferenceValidator<'q, 'd, 'a> {
+    validated: &'a ValidatedAst<'q>,
     diag: &'d mut Diagnostics,
     definitions: &'a CollectedDefinitions,
 }
 
-impl Visitor for ReferenceValidator<'_, '_> {
+impl Visitor for ReferenceValidator<'_, '_, '_> {
+    fn visit_def(&mut self, def: &Located<ast::Def>) {
+        if self.validated.admits(def.source(), def.node()) {
+            walk_def(self, def);
+        }
+    }
+
     fn visit_def_ref(&mut self, r: &Located<ast::DefRef>) {
         let Some(name_token) = r.node().name() else {
             return;
         };
         let name = name_token.text();
 
-        if self.definitions.defined_name(name).is_some() {
+        if self.definitions.defined_name(name).is_some() || self.names_excluded_def(name) {
             return;
         }
 
@@ -107,3 +120,16 @@ impl Visitor for ReferenceValidator<'_, '_> {
         builder.detail(name).emit();
     }
 }
+
+impl ReferenceValidator<'_, '_, '_> {
+    /// A definition left out of a provisional analysis still exists; a
+    /// reference to it is not undefined.
+    fn names_excluded_def(&self, name: &str) -> bool {
+        self.validated.ast_map().iter().any(|(&source, root)| {
+            root.defs().any(|def| {
+                def.name().is_some_and(|token| token.text() == name)
+                    && !self.validated.admits(source, &def)
+            })
+        })
+    }
+}
@@ -0,0 +1,46 @@
+//! One query split across several source files.
+//!
+//! Definitions share a single namespace across every source in the
+//! `SourceMap`, so a definition in one file is referenced from another like any
+//! local one, and diagnostics point at the file that holds the offending text.
+
+use crate::compiler::diagnostics::DiagnosticKind;
+use crate::compiler::query::{Query, QueryBuilder};
+use crate::compiler::{SourceMap, SourcePath};
+
+const MAIN: &str = "Main = (binary_expression left: (Expr) @lhs right: (Expr) @rhs)\n";
+
+fn analyze(lib: &str) -> Query {
+    let mut source_map = SourceMap::new();
+    source_map.add_file(SourcePath::new("main.ptk"), MAIN);
+    source_map.add_file(SourcePath::new("lib.ptk"), lib);
+    QueryBuilder::new(source_map)
+        .analyze()
+        .expect("query within parse limits")
+}
+
+#[test]
+fn reference_resolves_across_files() {
+    let query = analyze("Expr = [(identifier) (number)]\n");
+
+    assert!(
+        query.is_valid(),
+        "{}",
+        query.diagnostics().render(query.source_map())
+    );
+    assert!(query.definition_names().any(|name| name == "Main"));
+}
+
+#[test]
+fn diagnostic_names_the_defining_file() {
+    let query = analyze("Expr = [(identifier) (Missing)]\n");
+
+    assert!(!query.is_valid());
+    assert_eq!(
+        query.diagnostics().kinds().collect::<Vec<_>>(),
+        [DiagnosticKind::UndefinedReference]
+    );
+    let rendered = query.diagnostics().render(query.source_map());
+    assert!(rendered.contains(" --> lib.ptk:1:23"), "{rendered}");
+    assert!(!rendered.contains("main.ptk"), "{rendered}");
+}
@@ -12,6 +12,7 @@ use crate::compiler::ids::DefId;
 use crate::compiler::parse::ast::{self, DefRef, Pattern};
 use crate::core::{Interner, Symbol};
 
+#[derive(Clone)]
 pub(crate) struct Definition {
     name: Symbol,
     source: SourceId,
@@ -45,21 +46,39 @@ impl Definition {
         &self.body
     }
 
+    /// Definitions referenced from the body, each once, in first-reference order.
+    pub(crate) fn outgoing(&self) -> &[DefId] {
+        &self.outgoing
+    }
+
     pub(crate) fn located_body(&self) -> Located<Pattern> {
         Located::new(self.source, self.body.clone())
     }
 
     pub(crate) fn span(&self) -> Span {
-        let definition = self
-            .body
+        Span::new(self.source, self.def().syntax().text_range())
+    }
+
+    pub(crate) fn name_span(&self) -> Span {
+        let name = self.def().name().expect("admitted definition has a name");
+        Span::new(self.source, name.text_range())
+    }
+
+    /// Comment lines written directly above the definition.
+    pub(crate) fn doc_comment(&self) -> Option<Vec<String>> {
+        self.def().doc_comment()
+    }
+
+    fn def(&self) -> ast::Def {
+        self.body
             .syntax()
             .parent()
             .and_then(ast::Def::cast)
-            .expect("admitted definition body belongs to a definition");
-        Span::new(self.source, definition.syntax().text_range())
+            .expect("admitted definition body belongs to a definition")
     }
 }
 
+#[derive(Clone)]
 pub(crate) struct DefinitionGraph {
     /// Strongly connected components in reverse topological order.
     ///
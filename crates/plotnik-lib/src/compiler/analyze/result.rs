@@ -531,6 +531,53 @@ impl<'a> ResultSchema<'a> {
     pub(crate) fn public_result_group(&self, item: ResultItem) -> &PublicResultGroup {
         self.model.public_result_group(item)
     }
+
+    /// Fields of a record output, in member-slot order. Source targets sort
+    /// by rendered name themselves.
+    pub(crate) fn record_members(&self, ty: TypeId) -> Vec<(Symbol, RecordField, ResultMemberId)> {
+        let scope = self
+            .layout()
+            .scope(ty)
+            .expect("record output has a capture scope");
+        assert_eq!(
+            scope.kind(),
+            CaptureScopeKind::Record,
+            "record output must have a record capture scope"
+        );
+        scope
+            .members()
+            .map(|member| {
+                let descriptor = self.layout().expect_member(member);
+                let CaptureMemberKind::Field(info) = descriptor.kind else {
+                    unreachable!("record capture scope contains only field members");
+                };
+                (descriptor.name, info, member)
+            })
+            .collect()
+    }
+
+    /// Cases of a variant output, in member-slot order.
+    pub(crate) fn variant_members(&self, ty: TypeId) -> Vec<(Symbol, CasePayload, ResultMemberId)> {
+        let scope = self
+            .layout()
+            .scope(ty)
+            .expect("variant output has a capture scope");
+        assert_eq!(
+            scope.kind(),
+            CaptureScopeKind::Variant,
+            "variant output must have a variant capture scope"
+        );
+        scope
+            .members()
+            .map(|member| {
+                let descriptor = self.layout().expect_member(member);
+                let CaptureMemberKind::Case(payload) = descriptor.kind else {
+                    unreachable!("variant capture scope contains only case members");
+                };
+                (descriptor.name, payload, member)
+            })
+            .collect()
+    }
 }
 
 struct ItemCollector<'a> {
@@ -95,6 +95,7 @@ impl PatternSummary {
     }
 }
 
+#[derive(Clone)]
 struct DefinitionFacts {
     nullable: bool,
     root_extent: RootExtent,
@@ -102,6 +103,7 @@ struct DefinitionFacts {
     boundary: BoundaryRelation,
 }
 
+#[derive(Clone)]
 struct AuthoredPatternFacts {
     nullable: bool,
     root_extent: RootExtent,
@@ -110,6 +112,7 @@ struct AuthoredPatternFacts {
 }
 
 /// Frozen classification of every admitted definition and authored pattern.
+#[derive(Clone)]
 pub(crate) struct PatternFacts {
     definitions: Vec<DefinitionFacts>,
     patterns: HashMap<Pattern, AuthoredPatternFacts>,
@@ -2,6 +2,7 @@
 //!
 //! Validates semantic constraints that aren't captured by parsing or type checking:
 //! - Alternative-label consistency
+//! - Alternatives shadowed by an earlier wildcard (unreachable_alternatives)
 //! - Local shape rules; cross-definition anchor context is validated after
 //!   name and dependency analysis
 //! - Empty constructs (empty_constructs)
@@ -13,13 +14,15 @@ use indexmap::IndexMap;
 use crate::compiler::diagnostics::report::Diagnostics;
 use crate::compiler::diagnostics::source::SourceId;
 use crate::compiler::diagnostics::source::SourceMap;
-use crate::compiler::parse::ast::Root;
+use crate::compiler::diagnostics::span::Span;
+use crate::compiler::parse::ast::{Def, Root};
 
 pub mod alternative_labels;
 pub mod anchors;
 pub mod empty_constructs;
 pub mod predicates;
 pub mod strings;
+pub mod unreachable_alternatives;
 
 /// Inputs for the AST-only validation passes (alternative labeling, anchors, empty
 /// constructs).
@@ -49,10 +52,17 @@ pub struct ShapeValidationInput<'q, 'd> {
 pub(crate) struct ValidatedAst<'q> {
     source_map: &'q SourceMap,
     ast_map: &'q IndexMap<SourceId, Root>,
+    /// Definitions an error falls in, left out of a provisional analysis.
+    /// Always empty past [`validate_ast`].
+    excluded: Vec<Span>,
 }
 
 impl<'q> ValidatedAst<'q> {
-    fn new(source_map: &'q SourceMap, ast_map: &'q IndexMap<SourceId, Root>) -> Self {
+    fn new(
+        source_map: &'q SourceMap,
+        ast_map: &'q IndexMap<SourceId, Root>,
+        excluded: Vec<Span>,
+    ) -> Self {
         assert_eq!(
             source_map.len(),
             ast_map.len(),
@@ -68,6 +78,7 @@ impl<'q> ValidatedAst<'q> {
         Self {
             source_map,
             ast_map,
+            excluded,
         }
     }
 
@@ -78,41 +89,102 @@ impl<'q> ValidatedAst<'q> {
     pub(crate) fn ast_map(&self) -> &'q IndexMap<SourceId, Root> {
         self.ast_map
     }
+
+    /// Whether `def` takes part in analysis.
+    pub(crate) fn admits(&self, source: SourceId, def: &Def) -> bool {
+        !self
+            .excluded
+            .contains(&Span::new(source, def.syntax().text_range()))
+    }
 }
 
 pub fn validate_ast<'q>(input: ShapeValidationInput<'q, '_>) -> Option<ValidatedAst<'q>> {
-    for source in input.source_map.iter() {
-        let ast = input
-            .ast_map
+    let ShapeValidationInput {
+        source_map,
+        ast_map,
+        diag,
+    } = input;
+    report_shape_errors(source_map, ast_map, diag);
+    (!diag.has_errors()).then(|| ValidatedAst::new(source_map, ast_map, Vec::new()))
+}
+
+/// Like [`validate_ast`], but admits an AST with errors for provisional
+/// analysis. Definitions an error falls in are left out; the rest go on.
+pub(crate) fn validate_ast_provisionally<'q>(
+    input: ShapeValidationInput<'q, '_>,
+) -> ValidatedAst<'q> {
+    let ShapeValidationInput {
+        source_map,
+        ast_map,
+        diag,
+    } = input;
+    report_shape_errors(source_map, ast_map, diag);
+    let excluded = erroneous_definitions(ast_map, diag);
+    ValidatedAst::new(source_map, ast_map, excluded)
+}
+
+fn report_shape_errors(
+    source_map: &SourceMap,
+    ast_map: &IndexMap<SourceId, Root>,
+    diag: &mut Diagnostics,
+) {
+    for source in source_map.iter() {
+        let ast = ast_map
             .get(&source.id)
             .expect("parsed source must have an AST");
         validate_alternative_labels(ValidationInput {
             source_id: source.id,
             ast,
-            diag: &mut *input.diag,
+            diag: &mut *diag,
+        });
+        validate_unreachable_alternatives(ValidationInput {
+            source_id: source.id,
+            ast,
+            diag: &mut *diag,
         });
         validate_empty_constructs(ValidationInput {
             source_id: source.id,
             ast,
-            diag: &mut *input.diag,
+            diag: &mut *diag,
         });
         validate_predicates(PredicateInput {
             source_id: source.id,
             ast,
             source_content: source.content,
-            diag: &mut *input.diag,
+            diag: &mut *diag,
         });
         strings::validate_strings(ValidationInput {
             source_id: source.id,
             ast,
-            diag: &mut *input.diag,
+            diag: &mut *diag,
         });
     }
+}
 
-    (!input.diag.has_errors()).then(|| ValidatedAst::new(input.source_map, input.ast_map))
+/// Each error belongs to the last definition starting at or before it, so an
+/// error reported past a definition's end (an unclosed paren at end of input)
+/// still excludes that definition.
+fn erroneous_definitions(ast_map: &IndexMap<SourceId, Root>, diag: &Diagnostics) -> Vec<Span> {
+    let mut excluded = Vec::new();
+    for (&source, root) in ast_map {
+        let defs: Vec<_> = root.defs().map(|def| def.syntax().text_range()).collect();
+        for error in diag.error_spans().filter(|span| span.source == source) {
+            let owner = defs
+                .iter()
+                .rev()
+                .find(|range| range.start() <= error.range.start());
+            if let Some(&range) = owner
+                && !excluded.contains(&Span::new(source, range))
+            {
+                excluded.push(Span::new(source, range));
+            }
+        }
+    }
+    excluded
 }
 
 pub use alternative_labels::validate_alternative_labels;
 pub(crate) use anchors::{AnchorValidationInput, validate_anchors};
 pub use empty_constructs::validate_empty_constructs;
 pub use predicates::validate_predicates;
+pub use unreachable_alternatives::validate_unreachable_alternatives;
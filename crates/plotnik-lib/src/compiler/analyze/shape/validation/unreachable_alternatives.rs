@@ -0,0 +1,115 @@
+//! Unreachable-alternative warning.
+//!
+//! Alternatives are tried left-to-right, and a later alternative only runs at a
+//! node after every earlier one failed there. A bare wildcard earlier in the
+//! alternation accepts every node a later single-node alternative could match:
+//! `_` shadows any node pattern, `(_)` any named one. The later alternative can
+//! then never be selected, and its captures or label never reach a result.
+
+use super::ValidationInput;
+use crate::compiler::diagnostics::report::DiagnosticKind;
+use crate::compiler::diagnostics::span::Span;
+use crate::compiler::parse::ast::{Alternative, Pattern};
+use crate::compiler::parse::cst::{SyntaxKind, SyntaxNode};
+
+pub fn validate_unreachable_alternatives(input: ValidationInput) {
+    let ValidationInput {
+        source_id,
+        ast,
+        diag,
+    } = input;
+
+    for node in ast.syntax().descendants() {
+        if node.kind() != SyntaxKind::Alternation {
+            continue;
+        }
+        let Some(Pattern::Alternation(alternation)) = Pattern::cast(node) else {
+            continue;
+        };
+
+        let mut wildcard: Option<(Wildcard, Alternative)> = None;
+        for alternative in alternation.alternatives() {
+            let Some(body) = alternative.body() else {
+                continue;
+            };
+            if let Some((shadowing, earlier)) = &wildcard
+                && shadowing.covers(&body)
+            {
+                diag.report(
+                    DiagnosticKind::UnreachableAlternative,
+                    Span::new(source_id, alternative.text_range()),
+                )
+                .related_to(
+                    Span::new(source_id, earlier.text_range()),
+                    "shadowed by this alternative",
+                )
+                .emit();
+                continue;
+            }
+            let Some(found) = Wildcard::of(&body) else {
+                continue;
+            };
+            if wildcard
+                .as_ref()
+                .is_none_or(|(current, _)| found > *current)
+            {
+                wildcard = Some((found, alternative));
+            }
+        }
+    }
+}
+
+/// An alternative body that accepts a node without inspecting it, ordered by
+/// how much it accepts.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Wildcard {
+    /// `(_)` with no children or predicate: any named node.
+    AnyNamedNode,
+    /// `_`: any node, named or anonymous.
+    AnyNode,
+}
+
+impl Wildcard {
+    fn of(body: &Pattern) -> Option<Self> {
+        match strip_captures(body)? {
+            Pattern::NodeWildcard(_) => Some(Self::AnyNode),
+            Pattern::NamedNodePattern(node) if node.is_any() && is_leaf_pattern(node.syntax()) => {
+                Some(Self::AnyNamedNode)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether every node `body` can match is already accepted by `self`.
+    ///
+    /// Only single-node bodies are compared. `(ERROR)` and `(MISSING)` match
+    /// recovery nodes with their own rules, so they are never reported.
+    fn covers(self, body: &Pattern) -> bool {
+        match strip_captures(body) {
+            Some(Pattern::NamedNodePattern(node)) => {
+                let special = node.kind_token().is_some_and(|token| {
+                    matches!(token.kind(), SyntaxKind::KwError | SyntaxKind::KwMissing)
+                });
+                !special && !node.has_supertype_marker()
+            }
+            Some(Pattern::AnonymousNodePattern(_) | Pattern::NodeWildcard(_)) => {
+                self == Self::AnyNode
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The pattern a capture wraps; captures and capture types do not change
+/// which nodes match.
+fn strip_captures(pattern: &Pattern) -> Option<Pattern> {
+    let mut pattern = pattern.clone();
+    while let Pattern::CapturedPattern(captured) = pattern {
+        pattern = captured.inner()?;
+    }
+    Some(pattern)
+}
+
+fn is_leaf_pattern(syntax: &SyntaxNode) -> bool {
+    syntax.children().next().is_none()
+}
@@ -4,7 +4,7 @@ use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use super::planner::CaptureTypePlanner;
 use super::*;
-use crate::compiler::analyze::types::type_description::describe_type;
+use crate::compiler::analyze::types::type_description::{DescriptionLimits, describe_type};
 use crate::compiler::analyze::types::type_shape::{
     CasePayload, ListMinimum, RecordField, TYPE_NODE, TypeId, TypeShape,
 };
@@ -968,8 +968,18 @@ impl<'s, 'c, 'a, 'd> FieldFlowNormalizer<'s, 'c, 'a, 'd> {
                     // mismatch here can only be introduced by written capture
                     // types, so keep its field sources intact for later fields.
                     let types = self.session.types.in_progress();
-                    let left_type = describe_type(&types, self.session.interner, previous_type);
-                    let right_type = describe_type(&types, self.session.interner, right_type);
+                    let left_type = describe_type(
+                        &types,
+                        self.session.interner,
+                        previous_type,
+                        DescriptionLimits::DIAGNOSTIC,
+                    );
+                    let right_type = describe_type(
+                        &types,
+                        self.session.interner,
+                        right_type,
+                        DescriptionLimits::DIAGNOSTIC,
+                    );
                     self.session
                         .diagnostics
                         .report(
@@ -7,6 +7,8 @@ pub mod type_analysis;
 pub mod type_check;
 mod type_description;
 pub mod type_shape;
+mod unused_captures;
+mod unused_definitions;
 
 pub use capture::{
     BuiltInCaptureType, CaptureFact, CaptureKind, CaptureTypePlan, CaptureTypePlanKind,
@@ -15,3 +17,5 @@ pub use capture::{
 pub use entry_points::check_entry_points;
 pub use type_analysis::TypeAnalysis;
 pub use type_shape::TypeShape;
+pub use unused_captures::check_unused_captures;
+pub use unused_definitions::check_unused_definitions;
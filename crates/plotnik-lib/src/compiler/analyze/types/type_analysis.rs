@@ -16,6 +16,7 @@ use crate::compiler::analyze::Located;
 use crate::compiler::analyze::types::capture::{
     CaptureId, CaptureObservation, CaptureProvenance, FieldSource,
 };
+use crate::compiler::analyze::types::type_description::{DescriptionLimits, describe_type};
 use crate::compiler::analyze::types::type_shape::{
     CasePayload, DefinitionOutput, ListMinimum, PatternFlow, PatternShape,
     RESERVED_NO_VALUE_TYPE_ID, RecordField, TYPE_BOOL, TYPE_NODE, TYPE_TEXT, TypeId, TypeShape,
@@ -25,7 +26,7 @@ use crate::compiler::diagnostics::report::Diagnostics;
 use crate::compiler::diagnostics::span::Span;
 use crate::compiler::ids::{DefId, TypeDeclId};
 use crate::compiler::parse::ast::{CapturedPattern, Pattern};
-use crate::core::Symbol;
+use crate::core::{Interner, Symbol};
 
 /// One custom `:: TypeName` occurrence, recorded during inference for the
 /// naming pass to validate (nominal identity, collisions, redundancy).
@@ -560,6 +561,30 @@ impl TypeAnalysis {
         self.named_types.get(&type_id).copied()
     }
 
+    /// Short human-readable rendering of a type, as diagnostics show it.
+    pub(crate) fn describe_type(&self, interner: &Interner, type_id: TypeId) -> String {
+        self.describe_type_with(interner, type_id, DescriptionLimits::DIAGNOSTIC)
+    }
+
+    /// The same rendering as [`Self::describe_type`] with nothing elided.
+    pub(crate) fn describe_type_in_full(&self, interner: &Interner, type_id: TypeId) -> String {
+        self.describe_type_with(interner, type_id, DescriptionLimits::FULL)
+    }
+
+    fn describe_type_with(
+        &self,
+        interner: &Interner,
+        type_id: TypeId,
+        limits: DescriptionLimits,
+    ) -> String {
+        let pattern_shapes = HashMap::new();
+        let view = TypeAnalysisView {
+            analysis: self,
+            pattern_shapes: &pattern_shapes,
+        };
+        describe_type(&view, interner, type_id, limits)
+    }
+
     /// Iterate generated and explicitly named structural bodies in `TypeId`
     /// order. Definition declarations are exposed separately through their
     /// `DefId` and output body.
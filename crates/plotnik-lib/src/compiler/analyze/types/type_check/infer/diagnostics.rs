@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use crate::compiler::analyze::shape::RootExtent;
 use crate::compiler::analyze::types::type_analysis::UnifyError;
-use crate::compiler::analyze::types::type_description::describe_type;
+use crate::compiler::analyze::types::type_description::{DescriptionLimits, describe_type};
 use crate::compiler::analyze::types::type_shape::{PatternFlow, PatternShape, TypeId};
 use crate::compiler::diagnostics::report::DiagnosticKind;
 use crate::compiler::diagnostics::span::Span;
@@ -222,9 +222,11 @@ impl InferVisitor<'_, '_> {
             return false;
         }
 
-        let definition = self
-            .referenced_definition(inner)
-            .expect("resolved definition reference has definition provenance");
+        // An unresolved name, or one whose definition analysis left out,
+        // already has its own diagnostic.
+        let Some(definition) = self.referenced_definition(inner) else {
+            return false;
+        };
         let capture_name = self.ctx.interner.resolve(capture_name).to_string();
         let suggested_capture = format!("{capture_name}_value");
         let hint = result_capture_hint(
@@ -444,7 +446,12 @@ impl InferVisitor<'_, '_> {
     }
 
     fn describe_type(&self, type_id: TypeId) -> String {
-        describe_type(&self.ctx.type_ctx.in_progress(), self.ctx.interner, type_id)
+        describe_type(
+            &self.ctx.type_ctx.in_progress(),
+            self.ctx.interner,
+            type_id,
+            DescriptionLimits::DIAGNOSTIC,
+        )
     }
 }
 
@@ -34,25 +34,44 @@ impl Description {
     }
 }
 
+/// How much of a type a description spells out before eliding with `…`.
+#[derive(Clone, Copy)]
+pub(crate) struct DescriptionLimits {
+    depth: usize,
+    members: usize,
+}
+
+impl DescriptionLimits {
+    /// Short enough to sit inside a diagnostic message.
+    pub(crate) const DIAGNOSTIC: Self = Self {
+        depth: 4,
+        members: 6,
+    };
+    /// Every member at every depth; only recursion is cut.
+    pub(crate) const FULL: Self = Self {
+        depth: usize::MAX,
+        members: usize::MAX,
+    };
+}
+
 pub(crate) fn describe_type(
     types: &TypeAnalysisView<'_>,
     interner: &Interner,
     type_id: TypeId,
+    limits: DescriptionLimits,
 ) -> String {
-    describe_type_inner(types, interner, type_id, &mut HashSet::new(), 0).text
+    describe_type_inner(types, interner, type_id, limits, &mut HashSet::new(), 0).text
 }
 
 fn describe_type_inner(
     types: &TypeAnalysisView<'_>,
     interner: &Interner,
     type_id: TypeId,
+    limits: DescriptionLimits,
     seen: &mut HashSet<TypeId>,
     depth: usize,
 ) -> Description {
-    const MAX_DEPTH: usize = 4;
-    const MAX_MEMBERS: usize = 6;
-
-    if depth == MAX_DEPTH {
+    if depth == limits.depth {
         return Description::atom("…");
     }
     if !seen.insert(type_id) {
@@ -69,15 +88,21 @@ fn describe_type_inner(
         TypeShape::Record(fields) => {
             let mut descriptions = fields
                 .iter()
-                .take(MAX_MEMBERS)
+                .take(limits.members)
                 .map(|(name, field)| {
-                    let field_type =
-                        describe_type_inner(types, interner, field.final_type, seen, depth + 1);
+                    let field_type = describe_type_inner(
+                        types,
+                        interner,
+                        field.final_type,
+                        limits,
+                        seen,
+                        depth + 1,
+                    );
                     let name = interner.resolve(*name);
                     format!("{name}: {}", field_type.text)
                 })
                 .collect::<Vec<_>>();
-            if fields.len() > MAX_MEMBERS {
+            if fields.len() > limits.members {
                 descriptions.push("…".to_string());
             }
             Description::atom(format!("{{ {} }}", descriptions.join(", ")))
@@ -85,20 +110,26 @@ fn describe_type_inner(
         TypeShape::Variant(cases) => {
             let mut descriptions = cases
                 .iter()
-                .take(MAX_MEMBERS)
+                .take(limits.members)
                 .map(|(name, payload)| {
                     let name = interner.resolve(*name);
                     payload.type_id().map_or_else(
                         || name.to_string(),
                         |payload| {
-                            let payload =
-                                describe_type_inner(types, interner, payload, seen, depth + 1);
+                            let payload = describe_type_inner(
+                                types,
+                                interner,
+                                payload,
+                                limits,
+                                seen,
+                                depth + 1,
+                            );
                             format!("{name}({})", payload.text)
                         },
                     )
                 })
                 .collect::<Vec<_>>();
-            if cases.len() > MAX_MEMBERS {
+            if cases.len() > limits.members {
                 descriptions.push("…".to_string());
             }
             Description {
@@ -107,14 +138,14 @@ fn describe_type_inner(
             }
         }
         TypeShape::List { element, .. } => {
-            let element = describe_type_inner(types, interner, *element, seen, depth + 1);
+            let element = describe_type_inner(types, interner, *element, limits, seen, depth + 1);
             Description {
                 text: format!("{}[]", element.postfix_operand()),
                 precedence: Precedence::Postfix,
             }
         }
         TypeShape::Option(inner) => {
-            let inner = describe_type_inner(types, interner, *inner, seen, depth + 1);
+            let inner = describe_type_inner(types, interner, *inner, limits, seen, depth + 1);
             Description {
                 text: format!("{} | null", inner.text),
                 precedence: Precedence::Union,
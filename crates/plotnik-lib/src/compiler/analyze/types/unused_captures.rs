@@ -0,0 +1,164 @@
+//! Captures whose value can never reach a result.
+//!
+//! A definition's captures are materialized only when the definition is an
+//! entry point, when some reference to it is captured, or when it is the root
+//! of a definition whose own value is materialized (`Items = (Item)*`). A
+//! definition that is none of these — reached solely through bare references
+//! `(Name)` — matches structurally and its captures are dropped, which is
+//! almost always a mistake.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::compiler::analyze::Located;
+use crate::compiler::analyze::refs::DefinitionGraph;
+use crate::compiler::analyze::shape::PatternFacts;
+use crate::compiler::analyze::visitor::{Visitor, walk_captured_pattern};
+use crate::compiler::diagnostics::report::{DiagnosticKind, Diagnostics};
+use crate::compiler::diagnostics::span::Span;
+use crate::compiler::ids::DefId;
+use crate::compiler::parse::ast::{CapturedPattern, DefRef, Pattern};
+use crate::core::Interner;
+
+/// Warn on every field of a definition whose value nothing materializes.
+///
+/// Captures inside a scope boundary (`{...} @x`, `[...] @x`) are not reported
+/// separately: they are part of the boundary capture's value. Discards (`@_`)
+/// are intentionally inert and never warn, and a reference under a discard
+/// counts as acknowledged.
+pub fn check_unused_captures(
+    interner: &Interner,
+    definitions: &DefinitionGraph,
+    pattern_facts: &PatternFacts,
+    diag: &mut Diagnostics,
+) {
+    let mut uses = ReferenceUses {
+        definitions,
+        capture_depth: 0,
+        captured: HashSet::new(),
+        bare: HashMap::new(),
+    };
+    let mut forwards = Vec::new();
+    for &def_id in definitions.ids_in_declaration_order() {
+        let body = definitions.definition(def_id).located_body();
+        if let Some(target) = root_reference(definitions, body.node()) {
+            forwards.push((def_id, target));
+        }
+        uses.visit_pattern(&body);
+    }
+
+    let mut consumed: HashSet<DefId> = definitions
+        .ids_in_declaration_order()
+        .iter()
+        .copied()
+        .filter(|&def_id| pattern_facts.is_entry_point_eligible(def_id))
+        .chain(uses.captured.iter().copied())
+        .collect();
+    loop {
+        let before = consumed.len();
+        for &(alias, target) in &forwards {
+            if consumed.contains(&alias) {
+                consumed.insert(target);
+            }
+        }
+        if consumed.len() == before {
+            break;
+        }
+    }
+
+    for &def_id in definitions.ids_in_declaration_order() {
+        if consumed.contains(&def_id) {
+            continue;
+        }
+        // Unreferenced non-entry definitions are dead as a whole; that is not a
+        // capture problem.
+        let Some(&bare_ref) = uses.bare.get(&def_id) else {
+            continue;
+        };
+
+        let definition = definitions.definition(def_id);
+        let mut captures = ScopeFields::default();
+        captures.visit_pattern(&definition.located_body());
+
+        let def_name = interner.resolve(definition.name());
+        for (name, span) in captures.found {
+            diag.report(DiagnosticKind::UnusedCapture, span)
+                .detail(name)
+                .related_to(
+                    bare_ref,
+                    format!("`{def_name}` is referenced here without a capture"),
+                )
+                .emit();
+        }
+    }
+}
+
+/// The definition a body forwards its value from: `(Item)`, `(Item)*`, `(Item)?`.
+fn root_reference(definitions: &DefinitionGraph, body: &Pattern) -> Option<DefId> {
+    match body {
+        Pattern::DefRef(reference) => definitions.reference_target(reference),
+        Pattern::QuantifiedPattern(quant) => root_reference(definitions, &quant.inner()?),
+        _ => None,
+    }
+}
+
+/// A captured sequence or alternation, possibly quantified, is a scope boundary.
+fn opens_scope(pattern: Pattern) -> bool {
+    match pattern {
+        Pattern::SeqPattern(_) | Pattern::Alternation(_) => true,
+        Pattern::QuantifiedPattern(quant) => quant.inner().is_some_and(opens_scope),
+        _ => false,
+    }
+}
+
+/// Splits references into those some capture receives and bare ones.
+struct ReferenceUses<'a> {
+    definitions: &'a DefinitionGraph,
+    capture_depth: usize,
+    captured: HashSet<DefId>,
+    /// First bare reference per target, for the related span.
+    bare: HashMap<DefId, Span>,
+}
+
+impl Visitor for ReferenceUses<'_> {
+    fn visit_captured_pattern(&mut self, cap: &Located<CapturedPattern>) {
+        self.capture_depth += 1;
+        walk_captured_pattern(self, cap);
+        self.capture_depth -= 1;
+    }
+
+    fn visit_def_ref(&mut self, reference: &Located<DefRef>) {
+        let Some(target) = self.definitions.reference_target(reference.node()) else {
+            return;
+        };
+        if self.capture_depth > 0 {
+            self.captured.insert(target);
+            return;
+        }
+        self.bare
+            .entry(target)
+            .or_insert_with(|| reference.span_of(reference.node().text_range()));
+    }
+}
+
+/// Captures that become fields of the definition's own scope.
+#[derive(Default)]
+struct ScopeFields {
+    found: Vec<(String, Span)>,
+}
+
+impl Visitor for ScopeFields {
+    fn visit_captured_pattern(&mut self, cap: &Located<CapturedPattern>) {
+        let capture = cap.node().capture();
+        if capture.is_discard() {
+            return;
+        }
+        if let Some(token) = capture.name() {
+            let name = token.text().trim_start_matches('@').to_string();
+            self.found.push((name, cap.span_of(token.text_range())));
+        }
+        if capture.capture_type().is_some() || cap.node().inner().is_some_and(opens_scope) {
+            return;
+        }
+        walk_captured_pattern(self, cap);
+    }
+}
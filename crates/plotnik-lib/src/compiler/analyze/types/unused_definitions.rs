@@ -0,0 +1,44 @@
+//! Fragments no entry point can reach.
+//!
+//! A definition whose root matches exactly one node is an entry point, so it is
+//! live on its own. Sequence- and quantifier-rooted fragments only run through
+//! references from an entry point. When no entry point reaches a fragment,
+//! emission strips it and it never runs, which is almost always leftover code.
+
+use crate::compiler::analyze::refs::DefinitionGraph;
+use crate::compiler::analyze::shape::PatternFacts;
+use crate::compiler::diagnostics::report::{DiagnosticKind, Diagnostics};
+use crate::compiler::ids::DefId;
+use crate::core::Interner;
+
+/// Warn on every definition outside the reference closure of the entry points.
+///
+/// A query made only of fragments selects nothing, so it has no entry point to
+/// measure reachability from and is left alone.
+pub fn check_unused_definitions(
+    interner: &Interner,
+    definitions: &DefinitionGraph,
+    pattern_facts: &PatternFacts,
+    diag: &mut Diagnostics,
+) {
+    let entry_points: Vec<DefId> = definitions
+        .ids_in_declaration_order()
+        .iter()
+        .copied()
+        .filter(|&def_id| pattern_facts.is_entry_point_eligible(def_id))
+        .collect();
+    if entry_points.is_empty() {
+        return;
+    }
+
+    let reachable = definitions.reachable_from(entry_points);
+    for &def_id in definitions.ids_in_declaration_order() {
+        if reachable.contains(def_id) {
+            continue;
+        }
+        let definition = definitions.definition(def_id);
+        diag.report(DiagnosticKind::UnusedDefinition, definition.name_span())
+            .detail(interner.resolve(definition.name()))
+            .emit();
+    }
+}
@@ -0,0 +1,70 @@
+//! Editor completions for partially written queries.
+//!
+//! Works from the lexer alone, so it answers for text that does not parse yet.
+//! Only the node-kind position is covered so far: the cursor right after `(`,
+//! optionally inside a partially typed kind name.
+
+use crate::compiler::parse::{SyntaxKind, lex};
+use crate::core::grammar::Grammar;
+
+/// One candidate at the cursor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Completion {
+    /// Text to insert.
+    pub label: String,
+    /// Half-open byte span of query text the label replaces: the partially
+    /// typed name around the cursor, or an empty span at the cursor.
+    pub span: (u32, u32),
+}
+
+/// Candidates for the cursor at byte `offset` in `text`, in grammar order.
+///
+/// Right after `(`, every named node kind of `grammar` whose name starts with
+/// the text typed so far. Supertypes are left out, since they are not
+/// matchable on their own. Any other position yields no candidates.
+pub fn completions_at(text: &str, offset: u32, grammar: &Grammar) -> Vec<Completion> {
+    let cursor = offset as usize;
+    if !text.is_char_boundary(cursor) {
+        return Vec::new();
+    }
+
+    let tokens = lex(text);
+    let mut before = tokens
+        .iter()
+        .take_while(|token| usize::from(token.span.start()) < cursor)
+        .collect::<Vec<_>>();
+
+    // A name the cursor sits in or at the end of is the prefix being completed.
+    let mut span = (offset, offset);
+    if let Some(last) = before.last()
+        && last.kind == SyntaxKind::Id
+        && cursor <= usize::from(last.span.end())
+    {
+        span = (u32::from(last.span.start()), u32::from(last.span.end()));
+        before.pop();
+    }
+    let opens_node = before
+        .iter()
+        .rev()
+        .find(|token| !token.kind.is_trivia())
+        .is_some_and(|token| token.kind == SyntaxKind::ParenOpen);
+    if !opens_node {
+        return Vec::new();
+    }
+
+    let prefix = &text[span.0 as usize..cursor];
+    grammar
+        .all_named_node_kinds()
+        .into_iter()
+        .filter(|kind| kind.starts_with(prefix))
+        .filter(|kind| {
+            !grammar
+                .resolve_named_node(kind)
+                .is_some_and(|id| grammar.is_supertype(id))
+        })
+        .map(|kind| Completion {
+            label: kind.to_string(),
+            span,
+        })
+        .collect()
+}
@@ -0,0 +1,94 @@
+use indoc::indoc;
+
+use crate::compiler::complete::{Completion, completions_at};
+use crate::compiler::test_utils::synthetic_grammar as grammar;
+use crate::core::grammar::{Grammar, raw::RawGrammar};
+
+/// Labels offered with the cursor at the `|` in `query`.
+fn labels(query: &str) -> Vec<String> {
+    let offset = query.find('|').expect("query marks the cursor with `|`");
+    let text = query.replacen('|', "", 1);
+    completions_at(&text, offset as u32, grammar())
+        .into_iter()
+        .map(|completion| completion.label)
+        .collect()
+}
+
+/// `_expression` is a supertype over `binary` and `number`; `_operand` is a
+/// plain hidden rule.
+fn supertype_grammar() -> Grammar {
+    let raw = RawGrammar::from_json(indoc! {r#"
+        {
+          "name": "calc",
+          "rules": {
+            "program": { "type": "REPEAT", "content": { "type": "SYMBOL", "name": "_expression" } },
+            "_expression": {
+              "type": "CHOICE",
+              "members": [
+                { "type": "SYMBOL", "name": "binary" },
+                { "type": "SYMBOL", "name": "number" }
+              ]
+            },
+            "binary": {
+              "type": "PREC_LEFT",
+              "value": 1,
+              "content": {
+                "type": "SEQ",
+                "members": [
+                  { "type": "SYMBOL", "name": "_expression" },
+                  { "type": "STRING", "value": "+" },
+                  { "type": "SYMBOL", "name": "_operand" }
+                ]
+              }
+            },
+            "_operand": { "type": "SYMBOL", "name": "number" },
+            "number": { "type": "PATTERN", "value": "\\d+" }
+          },
+          "supertypes": ["_expression"]
+        }
+    "#})
+    .expect("hand-written grammar parses");
+    Grammar::from_raw(&raw).expect("hand-written grammar metadata")
+}
+
+#[test]
+fn empty_node_offers_matchable_named_kinds() {
+    let grammar = supertype_grammar();
+
+    let offered: Vec<_> = completions_at("Q = ()", 5, &grammar)
+        .into_iter()
+        .map(|completion| completion.label)
+        .collect();
+
+    assert!(grammar.all_named_node_kinds().contains(&"_expression"));
+    assert_eq!(offered, ["binary", "number", "program"]);
+}
+
+#[test]
+fn partial_name_filters_by_prefix_and_replaces_it() {
+    assert_eq!(
+        labels("Q = (c|"),
+        ["call_expression", "class_declaration", "comment"]
+    );
+
+    let completions = completions_at("Q = (program (lex)) @p", 16, grammar());
+    assert_eq!(
+        completions,
+        [Completion {
+            label: "lexical_declaration".to_string(),
+            span: (14, 17),
+        }]
+    );
+}
+
+#[test]
+fn whitespace_after_paren_still_expects_a_kind() {
+    assert_eq!(labels("Q = (program ( num|"), ["number"]);
+}
+
+#[test]
+fn positions_outside_a_node_kind_offer_nothing() {
+    assert!(labels("Q = |").is_empty());
+    assert!(labels("Q = (identifier) @i|").is_empty());
+    assert!(labels("Q = (program (identifier) |)").is_empty());
+}
@@ -0,0 +1,66 @@
+//! Machine application of diagnostic fixes.
+//!
+//! Every `Fix` replaces its diagnostic's display range. Applying them is a
+//! single left-to-right splice over one source; fixes whose ranges overlap have
+//! no well-defined combined result, so they are reported instead of guessed.
+
+use rowan::TextRange;
+
+use super::Diagnostics;
+use crate::compiler::diagnostics::source::Source;
+
+/// Two or more fixes in one source edit overlapping ranges.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{} pairs of fixes overlap", .conflicts.len())]
+pub struct FixConflict {
+    /// Each pair is `(earlier, later)` by start offset.
+    pub conflicts: Vec<(TextRange, TextRange)>,
+}
+
+impl Diagnostics {
+    /// Rewrite `source` with the fix of every live diagnostic located in it.
+    ///
+    /// Only fixes the user would see are applied: cascades suppressed by
+    /// [`Diagnostics::render`] are skipped. Identical edits (same range, same
+    /// replacement) collapse into one. Two edits conflict when their ranges
+    /// overlap or when both insert at the same offset.
+    pub fn apply_fixes(&self, source: Source<'_>) -> Result<String, FixConflict> {
+        let mut edits: Vec<(TextRange, &str)> = self
+            .live()
+            .into_iter()
+            .filter(|d| d.span.source == source.id)
+            .filter_map(|d| Some((d.span.range, d.fix.as_ref()?.replacement.as_str())))
+            .collect();
+        edits.sort_by_key(|(range, _)| (range.start(), range.end()));
+        edits.dedup();
+
+        // Edits are sorted by start, so scanning forward from each edit stops
+        // at the first one that begins past its end.
+        let mut conflicts = Vec::new();
+        for (i, &(a, _)) in edits.iter().enumerate() {
+            for &(b, _) in edits[i + 1..].iter().take_while(|(b, _)| overlaps(a, *b)) {
+                conflicts.push((a, b));
+            }
+        }
+        if !conflicts.is_empty() {
+            return Err(FixConflict { conflicts });
+        }
+
+        let content = source.content;
+        let mut output = String::with_capacity(content.len());
+        let mut cursor = 0;
+        for (range, replacement) in edits {
+            let range = std::ops::Range::<usize>::from(range);
+            output.push_str(&content[cursor..range.start]);
+            output.push_str(replacement);
+            cursor = range.end;
+        }
+        output.push_str(&content[cursor..]);
+        Ok(output)
+    }
+}
+
+/// `a` starts no later than `b` (edits are sorted).
+fn overlaps(a: TextRange, b: TextRange) -> bool {
+    b.start() < a.end() || b.start() == a.start()
+}
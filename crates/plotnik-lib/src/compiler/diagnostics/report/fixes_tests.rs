@@ -0,0 +1,74 @@
+use indoc::indoc;
+use rowan::{TextRange, TextSize};
+
+use super::{DiagnosticKind, Diagnostics, FixConflict};
+use crate::compiler::diagnostics::{SourceMap, Span};
+use crate::compiler::query::QueryBuilder;
+
+fn range(start: u32, end: u32) -> TextRange {
+    TextRange::new(TextSize::from(start), TextSize::from(end))
+}
+
+#[test]
+fn applies_capture_name_fix() {
+    let parsed = QueryBuilder::from_inline("Q = (identifier) @foo.bar")
+        .parse()
+        .expect("parse within limits");
+    let source = parsed.source_map().iter().next().expect("one source");
+
+    let fixed = parsed.diagnostics().apply_fixes(source);
+
+    assert_eq!(fixed, Ok("Q = (identifier) @foo_bar".to_string()));
+}
+
+#[test]
+fn applies_fixes_left_to_right() {
+    let input = indoc! {"
+        Q = [
+          left: (a) @foo.bar
+          right: (b)
+        ]
+    "};
+    let parsed = QueryBuilder::from_inline(input)
+        .parse()
+        .expect("parse within limits");
+    let source = parsed.source_map().iter().next().expect("one source");
+
+    let fixed = parsed.diagnostics().apply_fixes(source);
+
+    let expected = indoc! {"
+        Q = [
+          Left: (a) @foo_bar
+          Right: (b)
+        ]
+    "};
+    assert_eq!(fixed, Ok(expected.to_string()));
+}
+
+#[test]
+fn overlapping_fixes_conflict() {
+    let sources = SourceMap::from_inline("abcdefgh");
+    let source = sources.iter().next().expect("one source");
+    let mut diag = Diagnostics::new();
+    diag.report(
+        DiagnosticKind::CaptureNameInvalid,
+        Span::new(source.id, range(0, 5)),
+    )
+    .fix("first", "x")
+    .emit();
+    diag.report(
+        DiagnosticKind::DefNameInvalid,
+        Span::new(source.id, range(3, 8)),
+    )
+    .fix("second", "y")
+    .emit();
+
+    let fixed = diag.apply_fixes(source);
+
+    assert_eq!(
+        fixed,
+        Err(FixConflict {
+            conflicts: vec![(range(0, 5), range(3, 8))],
+        })
+    );
+}
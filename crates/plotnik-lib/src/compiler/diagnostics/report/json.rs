@@ -3,7 +3,9 @@
 //! The terminal renderer, CLI `--json` output, WASM API, and future LSP are
 //! all views over this schema. Field semantics:
 //!
-//! - `code`: stable snake_case identifier derived from [`DiagnosticKind`]
+//! - `code`: stable `PLTnnnn` identifier from [`DiagnosticKind::code`]; never
+//!   renumbered, so scripts can allow/deny diagnostics by it
+//! - `kind`: snake_case name of the [`DiagnosticKind`], for readability
 //! - `line`/`column`: 1-based; columns count Unicode scalar values
 //! - `offset`: byte offset into the source
 
@@ -14,7 +16,8 @@ use super::{SourceMap, message};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Diagnostic {
-    pub code: DiagnosticKind,
+    pub code: &'static str,
+    pub kind: DiagnosticKind,
     pub severity: Severity,
     pub message: String,
     pub span: Span,
@@ -65,7 +68,8 @@ impl From<&message::Fix> for Fix {
 impl Diagnostic {
     pub(crate) fn from_diagnostic(msg: &message::Diagnostic, sources: &SourceMap) -> Self {
         Self {
-            code: msg.kind,
+            code: msg.kind.code(),
+            kind: msg.kind,
             severity: msg.severity(),
             message: msg.message.clone(),
             span: wire_span(sources, msg.span),
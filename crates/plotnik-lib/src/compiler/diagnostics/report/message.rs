@@ -78,6 +78,9 @@ pub enum DiagnosticKind {
 
     IncompatibleTypes,
     UnusedAlternativeLabels,
+    UnreachableAlternative,
+    UnusedCapture,
+    UnusedDefinition,
     UncollectedQuantifiedCaptures,
     CaptureWithoutSingleNode,
     MatchOnlyReferenceCapture,
@@ -127,6 +130,8 @@ pub enum DiagnosticKind {
     TargetLimitExceeded,
     NoEntryPoints,
     EmptyQuery,
+
+    RepeatedSingleField,
 }
 
 impl DiagnosticKind {
@@ -134,10 +139,14 @@ impl DiagnosticKind {
     pub fn severity(&self) -> Severity {
         match self {
             Self::UnusedAlternativeLabels
+            | Self::UnreachableAlternative
+            | Self::UnusedCapture
+            | Self::UnusedDefinition
             | Self::CaptureTypeReplacesData
             | Self::RedundantCaptureType
             | Self::InspectionSpansDegraded
             | Self::EntryPointNeverMatchesRoot
+            | Self::RepeatedSingleField
             | Self::TreeSitterSequenceSyntaxDeprecated
             | Self::NegationSyntaxDeprecated
             | Self::SupertypeSlashDeprecated => Severity::Warning,
@@ -145,6 +154,116 @@ impl DiagnosticKind {
         }
     }
 
+    /// Stable identifier for scripting around diagnostics (CI allow/deny lists,
+    /// editor filters). Codes are assigned once and never reused or renumbered:
+    /// a new kind takes the next free number regardless of where its variant
+    /// sits in the priority order above.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UnclosedString => "PLT0001",
+            Self::UnclosedRegex => "PLT0002",
+            Self::UnclosedTree => "PLT0003",
+            Self::UnclosedSequence => "PLT0004",
+            Self::UnclosedAlternation => "PLT0005",
+            Self::ExpectedExpression => "PLT0006",
+            Self::ExpectedCaptureType => "PLT0007",
+            Self::ExpectedGrammarFieldName => "PLT0008",
+            Self::ExpectedSubtype => "PLT0009",
+            Self::ExpectedPredicateValue => "PLT0010",
+            Self::EmptyTree => "PLT0011",
+            Self::EmptyAnonymousNode => "PLT0012",
+            Self::EmptySequence => "PLT0013",
+            Self::EmptyAlternation => "PLT0014",
+            Self::BareIdentifier => "PLT0015",
+            Self::InvalidSeparator => "PLT0016",
+            Self::AnchorInAlternation => "PLT0017",
+            Self::QuantifiedAnchor => "PLT0018",
+            Self::CapturedAnchor => "PLT0019",
+            Self::AnchorAsGrammarFieldValue => "PLT0020",
+            Self::NegatedFieldInAlternation => "PLT0021",
+            Self::NegatedFieldInSequence => "PLT0022",
+            Self::QuantifiedNegatedField => "PLT0023",
+            Self::CapturedNegatedField => "PLT0024",
+            Self::NegatedFieldAsGrammarFieldValue => "PLT0025",
+            Self::InvalidGrammarFieldEquals => "PLT0026",
+            Self::InvalidSupertypeSyntax => "PLT0027",
+            Self::InvalidCaptureTypeSyntax => "PLT0028",
+            Self::ErrorTakesNoArguments => "PLT0029",
+            Self::MissingTakesNoChildren => "PLT0030",
+            Self::RefCannotHaveChildren => "PLT0031",
+            Self::ErrorMissingOutsideParens => "PLT0032",
+            Self::UnsupportedPredicate => "PLT0033",
+            Self::UnexpectedToken => "PLT0034",
+            Self::CaptureWithoutTarget => "PLT0035",
+            Self::CaptureTypeWithoutCapture => "PLT0036",
+            Self::CaptureNameInvalid => "PLT0037",
+            Self::DefNameInvalid => "PLT0038",
+            Self::AlternativeLabelInvalid => "PLT0039",
+            Self::GrammarFieldNameInvalid => "PLT0040",
+            Self::CaptureTypeNameInvalid => "PLT0041",
+            Self::TreeSitterSequenceSyntaxDeprecated => "PLT0042",
+            Self::NegationSyntaxDeprecated => "PLT0043",
+            Self::SupertypeSlashDeprecated => "PLT0044",
+            Self::DuplicateDefinition => "PLT0045",
+            Self::UndefinedReference => "PLT0046",
+            Self::MixedAlternativeLabels => "PLT0047",
+            Self::DuplicateAlternativeLabel => "PLT0048",
+            Self::RecursionWithoutEscape => "PLT0049",
+            Self::RecursionWithoutProgress => "PLT0050",
+            Self::GrammarFieldSequenceValue => "PLT0051",
+            Self::AnchorWithoutContext => "PLT0052",
+            Self::IncompatibleTypes => "PLT0053",
+            Self::UnusedAlternativeLabels => "PLT0054",
+            Self::UnusedCapture => "PLT0098",
+            Self::UnusedDefinition => "PLT0099",
+            Self::UncollectedQuantifiedCaptures => "PLT0055",
+            Self::CaptureWithoutSingleNode => "PLT0056",
+            Self::MatchOnlyReferenceCapture => "PLT0057",
+            Self::UnnamedQuantifiedElement => "PLT0058",
+            Self::NullableRepeat => "PLT0059",
+            Self::DuplicateCaptureInScope => "PLT0060",
+            Self::IncompatibleCaptureTypes => "PLT0061",
+            Self::IncompatibleRecordShapes => "PLT0062",
+            Self::TypeNameConflict => "PLT0063",
+            Self::UnknownCaptureType => "PLT0064",
+            Self::InvalidCaptureType => "PLT0065",
+            Self::CaptureTypeReplacesData => "PLT0066",
+            Self::RedundantCaptureType => "PLT0067",
+            Self::InspectionSpansDegraded => "PLT0068",
+            Self::EntryPointNeverMatchesRoot => "PLT0069",
+            Self::PredicateOnNonLeaf => "PLT0070",
+            Self::UnknownStringEscape => "PLT0071",
+            Self::InvalidUnicodeEscape => "PLT0072",
+            Self::EmptyRegex => "PLT0073",
+            Self::RegexBackreference => "PLT0074",
+            Self::RegexLookaround => "PLT0075",
+            Self::RegexNamedCapture => "PLT0076",
+            Self::RegexMultilineFlag => "PLT0077",
+            Self::RegexCrlfFlag => "PLT0078",
+            Self::RegexBoundaryVariant => "PLT0079",
+            Self::RegexSyntaxError => "PLT0080",
+            Self::PredicateValueMismatch => "PLT0081",
+            Self::UnknownNodeKind => "PLT0082",
+            Self::MissingKindNotToken => "PLT0083",
+            Self::UnknownGrammarField => "PLT0084",
+            Self::GrammarFieldNotOnNodeKind => "PLT0085",
+            Self::InvalidGrammarFieldChildKind => "PLT0086",
+            Self::InvalidChildType => "PLT0087",
+            Self::UnsupportedSupertype => "PLT0088",
+            Self::BareSupertype => "PLT0089",
+            Self::ChildUnderLeafToken => "PLT0090",
+            Self::NegatedRequiredField => "PLT0091",
+            Self::RepeatedSingleField => "PLT0100",
+            Self::UnreachableAlternative => "PLT0101",
+            Self::UnsatisfiablePattern => "PLT0092",
+            Self::QueryTooComplex => "PLT0093",
+            Self::MissingDefName => "PLT0094",
+            Self::TargetLimitExceeded => "PLT0095",
+            Self::NoEntryPoints => "PLT0096",
+            Self::EmptyQuery => "PLT0097",
+        }
+    }
+
     /// Whether this kind suppresses `other` when spans overlap.
     ///
     /// Uses enum discriminant ordering: lower position = higher priority.
@@ -246,6 +365,15 @@ impl DiagnosticKind {
             Self::UnusedAlternativeLabels => {
                 "capture the alternation (`[...] @name`) to make its labels produce variant cases, or remove them"
             }
+            Self::UnreachableAlternative => {
+                "an earlier wildcard alternative matches every node this one does; move this alternative before it, or remove it"
+            }
+            Self::UnusedCapture => {
+                "a definition's captures reach a result only through an entry point or a captured reference `(Name) @name`"
+            }
+            Self::UnusedDefinition => {
+                "a sequence- or quantifier-rooted definition runs only when an entry point references it; reference it or remove it"
+            }
             Self::UnclosedTree => "add `)` to close the node",
             Self::UnclosedSequence => "add `}` to close the sequence",
             Self::UnclosedAlternation => "add `]` to close the alternation",
@@ -375,6 +503,9 @@ impl DiagnosticKind {
             Self::AnchorWithoutContext => "anchor needs an enclosing node",
             Self::IncompatibleTypes => "incompatible types",
             Self::UnusedAlternativeLabels => "alternative labels have no output effect here",
+            Self::UnreachableAlternative => "alternative can never be selected",
+            Self::UnusedCapture => "capture is never part of a result",
+            Self::UnusedDefinition => "definition is unreachable from every entry point",
             Self::UncollectedQuantifiedCaptures => {
                 "captures under a quantifier must be collected together"
             }
@@ -426,6 +557,7 @@ impl DiagnosticKind {
             Self::BareSupertype => "supertype is not a matchable node kind",
             Self::ChildUnderLeafToken => "leaf tokens have no child nodes",
             Self::NegatedRequiredField => "this grammar field is always present",
+            Self::RepeatedSingleField => "this grammar field holds at most one child",
             Self::UnsatisfiablePattern => "pattern can never match",
             Self::QueryTooComplex => "query too complex to compile",
             Self::MissingDefName => "definition must be named",
@@ -471,6 +603,8 @@ impl DiagnosticKind {
             Self::DuplicateCaptureInScope => {
                 "capture `@{}` already defined in this scope".to_string()
             }
+            Self::UnusedCapture => "capture `@{}` is never part of a result".to_string(),
+            Self::UnusedDefinition => "`{}` is unreachable from every entry point".to_string(),
             Self::IncompatibleRecordShapes => {
                 "capture `@{}` has incompatible record fields across alternatives".to_string()
             }
@@ -486,6 +620,7 @@ impl DiagnosticKind {
             Self::BareSupertype => "`{}` is a supertype, not a node kind".to_string(),
             Self::ChildUnderLeafToken => "`{}` is a leaf token and has no child nodes".to_string(),
             Self::NegatedRequiredField => "`-{}` can never match".to_string(),
+            Self::RepeatedSingleField => "{}".to_string(),
             // The detail, when present, is the crafted message; bare emits use the summary.
             Self::UnsatisfiablePattern => "{}".to_string(),
             Self::MixedAlternativeLabels => {
@@ -0,0 +1,235 @@
+use std::collections::HashSet;
+
+use super::DiagnosticKind::{self, *};
+
+/// Every kind, in declaration order. `DiagnosticKind::code` is an exhaustive
+/// match, so a new variant cannot ship without a code; add it here as well so
+/// the table below pins it.
+const ALL: &[DiagnosticKind] = &[
+    UnclosedString,
+    UnclosedRegex,
+    UnclosedTree,
+    UnclosedSequence,
+    UnclosedAlternation,
+    ExpectedExpression,
+    ExpectedCaptureType,
+    ExpectedGrammarFieldName,
+    ExpectedSubtype,
+    ExpectedPredicateValue,
+    EmptyTree,
+    EmptyAnonymousNode,
+    EmptySequence,
+    EmptyAlternation,
+    BareIdentifier,
+    InvalidSeparator,
+    AnchorInAlternation,
+    QuantifiedAnchor,
+    CapturedAnchor,
+    AnchorAsGrammarFieldValue,
+    NegatedFieldInAlternation,
+    NegatedFieldInSequence,
+    QuantifiedNegatedField,
+    CapturedNegatedField,
+    NegatedFieldAsGrammarFieldValue,
+    InvalidGrammarFieldEquals,
+    InvalidSupertypeSyntax,
+    InvalidCaptureTypeSyntax,
+    ErrorTakesNoArguments,
+    MissingTakesNoChildren,
+    RefCannotHaveChildren,
+    ErrorMissingOutsideParens,
+    UnsupportedPredicate,
+    UnexpectedToken,
+    CaptureWithoutTarget,
+    CaptureTypeWithoutCapture,
+    CaptureNameInvalid,
+    DefNameInvalid,
+    AlternativeLabelInvalid,
+    GrammarFieldNameInvalid,
+    CaptureTypeNameInvalid,
+    TreeSitterSequenceSyntaxDeprecated,
+    NegationSyntaxDeprecated,
+    SupertypeSlashDeprecated,
+    DuplicateDefinition,
+    UndefinedReference,
+    MixedAlternativeLabels,
+    DuplicateAlternativeLabel,
+    RecursionWithoutEscape,
+    RecursionWithoutProgress,
+    GrammarFieldSequenceValue,
+    AnchorWithoutContext,
+    IncompatibleTypes,
+    UnusedAlternativeLabels,
+    UnreachableAlternative,
+    UnusedCapture,
+    UnusedDefinition,
+    UncollectedQuantifiedCaptures,
+    CaptureWithoutSingleNode,
+    MatchOnlyReferenceCapture,
+    UnnamedQuantifiedElement,
+    NullableRepeat,
+    DuplicateCaptureInScope,
+    IncompatibleCaptureTypes,
+    IncompatibleRecordShapes,
+    TypeNameConflict,
+    UnknownCaptureType,
+    InvalidCaptureType,
+    CaptureTypeReplacesData,
+    RedundantCaptureType,
+    InspectionSpansDegraded,
+    EntryPointNeverMatchesRoot,
+    PredicateOnNonLeaf,
+    UnknownStringEscape,
+    InvalidUnicodeEscape,
+    EmptyRegex,
+    RegexBackreference,
+    RegexLookaround,
+    RegexNamedCapture,
+    RegexMultilineFlag,
+    RegexCrlfFlag,
+    RegexBoundaryVariant,
+    RegexSyntaxError,
+    PredicateValueMismatch,
+    UnknownNodeKind,
+    MissingKindNotToken,
+    UnknownGrammarField,
+    GrammarFieldNotOnNodeKind,
+    InvalidGrammarFieldChildKind,
+    InvalidChildType,
+    UnsupportedSupertype,
+    BareSupertype,
+    ChildUnderLeafToken,
+    NegatedRequiredField,
+    UnsatisfiablePattern,
+    QueryTooComplex,
+    MissingDefName,
+    TargetLimitExceeded,
+    NoEntryPoints,
+    EmptyQuery,
+    RepeatedSingleField,
+];
+
+#[test]
+fn codes_are_unique() {
+    let mut seen = HashSet::new();
+
+    for kind in ALL {
+        assert!(seen.insert(kind.code()), "{kind:?} reuses {}", kind.code());
+    }
+}
+
+/// Codes are a public contract: scripts allow/deny diagnostics by them. A diff
+/// here must only ever add lines — never renumber or reuse an existing code.
+#[test]
+fn code_table_is_stable() {
+    let mut kinds = ALL.to_vec();
+    kinds.sort_by_key(|kind| kind.code());
+    let table: String = kinds
+        .iter()
+        .map(|kind| format!("{} {kind:?}\n", kind.code()))
+        .collect();
+
+    insta::assert_snapshot!(table, @r"
+    PLT0001 UnclosedString
+    PLT0002 UnclosedRegex
+    PLT0003 UnclosedTree
+    PLT0004 UnclosedSequence
+    PLT0005 UnclosedAlternation
+    PLT0006 ExpectedExpression
+    PLT0007 ExpectedCaptureType
+    PLT0008 ExpectedGrammarFieldName
+    PLT0009 ExpectedSubtype
+    PLT0010 ExpectedPredicateValue
+    PLT0011 EmptyTree
+    PLT0012 EmptyAnonymousNode
+    PLT0013 EmptySequence
+    PLT0014 EmptyAlternation
+    PLT0015 BareIdentifier
+    PLT0016 InvalidSeparator
+    PLT0017 AnchorInAlternation
+    PLT0018 QuantifiedAnchor
+    PLT0019 CapturedAnchor
+    PLT0020 AnchorAsGrammarFieldValue
+    PLT0021 NegatedFieldInAlternation
+    PLT0022 NegatedFieldInSequence
+    PLT0023 QuantifiedNegatedField
+    PLT0024 CapturedNegatedField
+    PLT0025 NegatedFieldAsGrammarFieldValue
+    PLT0026 InvalidGrammarFieldEquals
+    PLT0027 InvalidSupertypeSyntax
+    PLT0028 InvalidCaptureTypeSyntax
+    PLT0029 ErrorTakesNoArguments
+    PLT0030 MissingTakesNoChildren
+    PLT0031 RefCannotHaveChildren
+    PLT0032 ErrorMissingOutsideParens
+    PLT0033 UnsupportedPredicate
+    PLT0034 UnexpectedToken
+    PLT0035 CaptureWithoutTarget
+    PLT0036 CaptureTypeWithoutCapture
+    PLT0037 CaptureNameInvalid
+    PLT0038 DefNameInvalid
+    PLT0039 AlternativeLabelInvalid
+    PLT0040 GrammarFieldNameInvalid
+    PLT0041 CaptureTypeNameInvalid
+    PLT0042 TreeSitterSequenceSyntaxDeprecated
+    PLT0043 NegationSyntaxDeprecated
+    PLT0044 SupertypeSlashDeprecated
+    PLT0045 DuplicateDefinition
+    PLT0046 UndefinedReference
+    PLT0047 MixedAlternativeLabels
+    PLT0048 DuplicateAlternativeLabel
+    PLT0049 RecursionWithoutEscape
+    PLT0050 RecursionWithoutProgress
+    PLT0051 GrammarFieldSequenceValue
+    PLT0052 AnchorWithoutContext
+    PLT0053 IncompatibleTypes
+    PLT0054 UnusedAlternativeLabels
+    PLT0055 UncollectedQuantifiedCaptures
+    PLT0056 CaptureWithoutSingleNode
+    PLT0057 MatchOnlyReferenceCapture
+    PLT0058 UnnamedQuantifiedElement
+    PLT0059 NullableRepeat
+    PLT0060 DuplicateCaptureInScope
+    PLT0061 IncompatibleCaptureTypes
+    PLT0062 IncompatibleRecordShapes
+    PLT0063 TypeNameConflict
+    PLT0064 UnknownCaptureType
+    PLT0065 InvalidCaptureType
+    PLT0066 CaptureTypeReplacesData
+    PLT0067 RedundantCaptureType
+    PLT0068 InspectionSpansDegraded
+    PLT0069 EntryPointNeverMatchesRoot
+    PLT0070 PredicateOnNonLeaf
+    PLT0071 UnknownStringEscape
+    PLT0072 InvalidUnicodeEscape
+    PLT0073 EmptyRegex
+    PLT0074 RegexBackreference
+    PLT0075 RegexLookaround
+    PLT0076 RegexNamedCapture
+    PLT0077 RegexMultilineFlag
+    PLT0078 RegexCrlfFlag
+    PLT0079 RegexBoundaryVariant
+    PLT0080 RegexSyntaxError
+    PLT0081 PredicateValueMismatch
+    PLT0082 UnknownNodeKind
+    PLT0083 MissingKindNotToken
+    PLT0084 UnknownGrammarField
+    PLT0085 GrammarFieldNotOnNodeKind
+    PLT0086 InvalidGrammarFieldChildKind
+    PLT0087 InvalidChildType
+    PLT0088 UnsupportedSupertype
+    PLT0089 BareSupertype
+    PLT0090 ChildUnderLeafToken
+    PLT0091 NegatedRequiredField
+    PLT0092 UnsatisfiablePattern
+    PLT0093 QueryTooComplex
+    PLT0094 MissingDefName
+    PLT0095 TargetLimitExceeded
+    PLT0096 NoEntryPoints
+    PLT0097 EmptyQuery
+    PLT0098 UnusedCapture
+    PLT0099 UnusedDefinition
+    PLT0100 RepeatedSingleField
+    PLT0101 UnreachableAlternative
+    ");
+}
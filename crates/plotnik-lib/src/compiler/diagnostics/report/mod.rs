@@ -1,9 +1,16 @@
+mod fixes;
 mod json;
 mod message;
 mod printer;
 
+#[cfg(test)]
+mod fixes_tests;
+#[cfg(test)]
+mod message_tests;
+
 use rowan::TextRange;
 
+pub use fixes::FixConflict;
 pub use json::{
     Diagnostic as JsonDiagnostic, Fix as JsonFix, Position as JsonPosition, Related as JsonRelated,
     Span as JsonSpan,
@@ -63,6 +70,14 @@ impl Diagnostics {
         self.messages.iter().filter(|d| d.is_error()).count()
     }
 
+    /// Primary spans of every error, suppressed cascades included.
+    pub(crate) fn error_spans(&self) -> impl Iterator<Item = Span> + '_ {
+        self.messages
+            .iter()
+            .filter(|d| d.is_error())
+            .map(|d| d.span)
+    }
+
     /// Returns diagnostics with cascading errors suppressed.
     ///
     /// All suppression is intra-file: offsets are per-source, so two diagnostics
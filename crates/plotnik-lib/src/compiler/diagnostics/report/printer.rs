@@ -76,7 +76,10 @@ impl<'q> DiagnosticsPrinter<'q> {
             }
 
             let level = severity_to_level(diag.severity());
-            let mut title_group = level.primary_title(&diag.message).element(primary_snippet);
+            let mut title_group = level
+                .primary_title(&diag.message)
+                .id(diag.kind.code())
+                .element(primary_snippet);
 
             for snippet in cross_file_snippets {
                 title_group = title_group.element(snippet);
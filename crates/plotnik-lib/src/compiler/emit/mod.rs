@@ -10,7 +10,8 @@ pub(crate) mod targets;
 
 pub use target::{
     BytecodeConfig, BytecodeInspection, CodegenProvenance, CodegenTarget, Emission,
-    EmitConfigError, EmitTarget, RustCodegenConfig, RustModuleOutput, RustTypesOutput,
+    EmitConfigError, EmitTarget, JsonSchemaConfig, JsonSchemaOutput, PythonCodegenConfig,
+    PythonTypesOutput, RustCodegenConfig, RustModuleOutput, RustTypesOutput,
     TypeScriptCodegenConfig, TypeScriptNodeRepresentation, TypeScriptTypesOutput,
 };
 
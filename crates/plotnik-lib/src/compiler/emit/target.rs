@@ -1,19 +1,21 @@
 //! Public target-dispatched emission API.
 
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 
 use plotnik_rt::{Limit, RuntimeLimitSpec};
 
 use crate::bytecode::Module;
 use crate::compiler::diagnostics::{Diagnostics, QueryResult};
 use crate::compiler::emit::targets::typescript::{MatchOnlyType, TypeScriptBinding};
-use crate::compiler::query::CompiledQuery;
+use crate::compiler::query::{CompiledQuery, PassTimings};
 use crate::core::Colors;
 
 /// The artifact and emission-local diagnostics produced by one target.
 pub struct Emission<T> {
     artifact: Option<T>,
     diagnostics: Diagnostics,
+    timings: Option<PassTimings>,
 }
 
 impl<T> Emission<T> {
@@ -21,6 +23,7 @@ impl<T> Emission<T> {
         Self {
             artifact: Some(artifact),
             diagnostics,
+            timings: None,
         }
     }
 
@@ -28,6 +31,7 @@ impl<T> Emission<T> {
         Self {
             artifact: None,
             diagnostics,
+            timings: None,
         }
     }
 
@@ -47,6 +51,18 @@ impl<T> Emission<T> {
         &self.diagnostics
     }
 
+    /// The compiled query's pass timings followed by this emission's `emit`
+    /// entry, when [`QueryBuilder::with_timing`](crate::QueryBuilder::with_timing)
+    /// was enabled.
+    pub fn timings(&self) -> Option<&PassTimings> {
+        self.timings.as_ref()
+    }
+
+    pub(crate) fn with_timings(mut self, timings: Option<PassTimings>) -> Self {
+        self.timings = timings;
+        self
+    }
+
     pub fn is_valid(&self) -> bool {
         self.artifact.is_some() && !self.diagnostics.has_errors()
     }
@@ -266,6 +282,11 @@ pub struct TypeScriptCodegenConfig {
     emit_node_interface: bool,
     include_points: bool,
     match_only_type: MatchOnlyType,
+    type_prefix: Option<String>,
+    type_suffix: Option<String>,
+    reserved_names: BTreeSet<String>,
+    readonly: bool,
+    validators: bool,
     colors: Colors,
     node_representation: TypeScriptNodeRepresentation,
 }
@@ -277,6 +298,11 @@ impl Default for TypeScriptCodegenConfig {
             emit_node_interface: true,
             include_points: false,
             match_only_type: MatchOnlyType::Undefined,
+            type_prefix: None,
+            type_suffix: None,
+            reserved_names: BTreeSet::new(),
+            readonly: false,
+            validators: false,
             colors: Colors::OFF,
             node_representation: TypeScriptNodeRepresentation::SerializedValue,
         }
@@ -308,6 +334,45 @@ impl TypeScriptCodegenConfig {
         self
     }
 
+    /// Prepend `prefix` to every generated type name (`Expr` becomes `QExpr`).
+    pub fn type_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.type_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Append `suffix` to every generated type name.
+    pub fn type_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.type_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Type names the generated declarations must not use, such as the
+    /// caller's own types. A generated name that lands on one, or on the
+    /// builtin `Node`, gets a numeric disambiguator: `Expr` becomes `Expr_1`.
+    pub fn reserved_names<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.reserved_names = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Mark record members and variant `$tag`/`$data` as `readonly`, and type
+    /// arrays as `ReadonlyArray<T>` or `readonly [T, ...T[]]`.
+    pub fn readonly(mut self, enabled: bool) -> Self {
+        self.readonly = enabled;
+        self
+    }
+
+    /// Follow the declarations with an `isX(value): value is X` type guard
+    /// per declared type, so consumers can check deserialized output at
+    /// runtime. The output is then a `.ts` module rather than a `.d.ts`.
+    pub fn validators(mut self, enabled: bool) -> Self {
+        self.validators = enabled;
+        self
+    }
+
     pub fn colored(mut self, enabled: bool) -> Self {
         self.colors = Colors::new(enabled);
         self
@@ -324,6 +389,11 @@ impl TypeScriptCodegenConfig {
             .emit_node_interface(self.emit_node_interface)
             .include_points(self.include_points)
             .match_only_type(self.match_only_type)
+            .type_prefix(self.type_prefix.clone())
+            .type_suffix(self.type_suffix.clone())
+            .reserved_names(self.reserved_names.clone())
+            .readonly(self.readonly)
+            .validators(self.validators)
             .colored(!self.colors.blue.is_empty())
     }
 
@@ -352,6 +422,81 @@ impl CodegenTarget for TypeScriptCodegenConfig {
     }
 }
 
+/// JSON Schema for the canonical JSON encoding of query results.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct JsonSchemaConfig {
+    entry_point: Option<String>,
+}
+
+impl JsonSchemaConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The entry point the document root validates. Defaults to the last one.
+    pub fn entry_point(mut self, name: impl Into<String>) -> Self {
+        self.entry_point = Some(name.into());
+        self
+    }
+
+    pub(crate) fn root(&self, query: &CompiledQuery) -> Result<Option<String>, EmitConfigError> {
+        let mut names = query.entry_point_names();
+        let Some(requested) = &self.entry_point else {
+            return Ok(names.last());
+        };
+        if !names.any(|name| &name == requested) {
+            return Err(EmitConfigError::new(format!(
+                "unknown entry point `{requested}`"
+            )));
+        }
+        Ok(Some(requested.clone()))
+    }
+}
+
+impl private::Sealed for JsonSchemaConfig {}
+
+impl CodegenTarget for JsonSchemaConfig {
+    type TypesOutput = JsonSchemaOutput;
+
+    fn emit_types(self, query: &CompiledQuery) -> QueryResult<Emission<Self::TypesOutput>> {
+        query.emit_json_schema(&self)
+    }
+}
+
+/// Python `TypedDict` (or dataclass) declarations for query results.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PythonCodegenConfig {
+    dataclasses: bool,
+}
+
+impl PythonCodegenConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare records as `@dataclass`es instead of `TypedDict`s. Dataclass
+    /// attributes drop the `$` of `$tag`/`$data`, so decoding JSON into them
+    /// needs an explicit mapping.
+    pub fn dataclasses(mut self, enabled: bool) -> Self {
+        self.dataclasses = enabled;
+        self
+    }
+
+    pub(crate) fn emitter_config(&self) -> crate::compiler::emit::targets::python::Config {
+        crate::compiler::emit::targets::python::Config::new().dataclasses(self.dataclasses)
+    }
+}
+
+impl private::Sealed for PythonCodegenConfig {}
+
+impl CodegenTarget for PythonCodegenConfig {
+    type TypesOutput = PythonTypesOutput;
+
+    fn emit_types(self, query: &CompiledQuery) -> QueryResult<Emission<Self::TypesOutput>> {
+        query.emit_python_types(&self)
+    }
+}
+
 macro_rules! source_output {
     ($name:ident) => {
         pub struct $name {
@@ -382,6 +527,7 @@ macro_rules! source_output {
 
 source_output!(RustModuleOutput);
 source_output!(RustTypesOutput);
+source_output!(PythonTypesOutput);
 
 pub struct TypeScriptTypesOutput {
     source: String,
@@ -405,3 +551,29 @@ impl TypeScriptTypesOutput {
         (self.source, self.bindings)
     }
 }
+
+pub struct JsonSchemaOutput {
+    schema: serde_json::Value,
+}
+
+impl JsonSchemaOutput {
+    pub(crate) fn new(schema: serde_json::Value) -> Self {
+        Self { schema }
+    }
+
+    pub fn schema(&self) -> &serde_json::Value {
+        &self.schema
+    }
+
+    pub fn into_schema(self) -> serde_json::Value {
+        self.schema
+    }
+
+    /// The document as indented JSON with a trailing newline.
+    pub fn to_pretty_string(&self) -> String {
+        let mut out =
+            serde_json::to_string_pretty(&self.schema).expect("JSON Schema serializes to JSON");
+        out.push('\n');
+        out
+    }
+}
@@ -0,0 +1,54 @@
+//! Byte-for-byte reproducibility of emitted bytecode.
+//!
+//! Every table that reaches the output is ordered by insertion or by key; hash
+//! maps only serve lookups. Each compilation builds fresh hash maps with their
+//! own random seeds, so compiling the same query twice would expose any output
+//! that leaked hash iteration order.
+
+use crate::compiler::query::QueryBuilder;
+use crate::compiler::test_utils::synthetic_grammar as grammar;
+use crate::compiler::{SourceMap, SourcePath};
+
+const QUERY: &str = r#"
+Decl = (variable_declarator
+  name: (identifier) @name
+  value: [
+    Str: (string) @text
+    Num: (number) @digits
+    Call: (call_expression
+      function: (identifier =~ /^(get|set)_/) @callee
+      arguments: (arguments (_)* @args)
+    )
+  ] @value
+)
+
+Member = (member_expression
+  object: (identifier == "self") @receiver
+  property: (identifier) @field
+)
+
+Q = (program
+  {
+    (lexical_declaration (Decl)+ @decls)
+    (expression_statement (Member)? @member)
+  }* @statements
+)
+"#;
+
+fn emit() -> Vec<u8> {
+    let mut source_map = SourceMap::new();
+    source_map.add_file(SourcePath::new("query.ptk"), QUERY);
+    let query = QueryBuilder::new(source_map)
+        .bind(grammar())
+        .expect("query parses");
+    assert!(query.is_valid(), "query should bind to the grammar");
+    query.emit_bytecode_for_test().expect("valid query emits")
+}
+
+#[test]
+fn repeated_compilation_emits_identical_bytes() {
+    let first = emit();
+    for _ in 0..8 {
+        assert_eq!(emit(), first, "bytecode differs between compilations");
+    }
+}
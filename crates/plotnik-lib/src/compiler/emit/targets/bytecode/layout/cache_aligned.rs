@@ -408,24 +408,23 @@ impl<'a> LayoutBuilder<'a> {
             });
             let size = instr.size() as u8;
 
-            // Prefer blocks that reference the predecessor block (cache locality)
-            let scores: Vec<_> = (0..current_block)
-                .map(|c| block_score(pred_block, c, &refs))
-                .collect();
+            // Prefer blocks that reference the predecessor block (cache locality).
+            // Only blocks with room are scored: most earlier blocks are full, and
+            // scoring them all makes packing quadratic in the block count.
             let best = (0..current_block)
                 .filter(|&c| self.arena.blocks[c].can_fit(size))
-                .max_by(|&a, &b| {
-                    scores[a].partial_cmp(&scores[b]).unwrap_or_else(|| {
+                .map(|c| (c, block_score(pred_block, c, &refs)))
+                .max_by(|(a, a_score), (b, b_score)| {
+                    a_score.partial_cmp(b_score).unwrap_or_else(|| {
                         panic!(
                             "cache-aligned layout cannot compare packing scores: block {a} has \
-                                 score {:?}, block {b} has score {:?}, candidate={succ_label:?}, \
-                                 predecessor_block={pred_block}",
-                            scores[a], scores[b]
+                                 score {a_score:?}, block {b} has score {b_score:?}, \
+                                 candidate={succ_label:?}, predecessor_block={pred_block}"
                         )
                     })
                 });
 
-            if let Some(candidate) = best {
+            if let Some((candidate, _)) = best {
                 self.arena.move_to(succ_label, candidate, size);
             }
         }
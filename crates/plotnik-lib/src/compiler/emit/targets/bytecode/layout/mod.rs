@@ -5,12 +5,16 @@ mod cache_aligned;
 
 pub use cache_aligned::CacheAligned;
 
+use indexmap::IndexMap;
+
+use crate::bytecode::BYTECODE_WORD_SIZE;
+use crate::compiler::analyze::AnalysisArtifacts;
 use crate::compiler::emit::targets::bytecode::layout_map::LayoutMap;
 use crate::compiler::emit::targets::bytecode::tables::EmitError;
-use crate::compiler::lower::ir::{Label, NfaGraph};
+use crate::compiler::lower::ir::{Label, LabelOrigin, NfaGraph};
 
 /// Assign a cache-aligned bytecode-word address to every label.
-pub fn compute_layout(ir: &NfaGraph) -> Result<LayoutMap, EmitError> {
+pub fn compute_layout(input: AnalysisArtifacts, ir: &NfaGraph) -> Result<LayoutMap, EmitError> {
     let entry_labels: Vec<Label> = ir
         .entry_points()
         .values()
@@ -28,12 +32,32 @@ pub fn compute_layout(ir: &NfaGraph) -> Result<LayoutMap, EmitError> {
     // Reject layouts whose bytecode-word addresses overflow the u16 address space.
     // `total_words` is computed in u32 precisely so this guard is reachable.
     if layout.total_words() > u16::MAX as u32 {
-        return Err(EmitError::TooManyInstructionWords(
-            layout.total_words() as usize
-        ));
+        return Err(EmitError::TooManyInstructionWords {
+            words: layout.total_words() as usize,
+            largest: largest_definition(input, ir),
+        });
     }
     Ok(layout)
 }
 
+/// The definition whose instructions occupy the most bytecode words, with
+/// that word count.
+///
+/// Padding and pack cascades have no source origin and are not attributed.
+fn largest_definition(input: AnalysisArtifacts, ir: &NfaGraph) -> Option<(String, usize)> {
+    let mut words_by_def = IndexMap::new();
+    for instruction in ir.instructions() {
+        let Some(LabelOrigin::Def(def_id) | LabelOrigin::DefSpecialization { def_id, .. }) =
+            ir.origin(instruction.label())
+        else {
+            continue;
+        };
+        *words_by_def.entry(def_id).or_insert(0) += instruction.size() / BYTECODE_WORD_SIZE;
+    }
+    let (def_id, words) = words_by_def.into_iter().max_by_key(|&(_, words)| words)?;
+    let name = input.definitions.definition(def_id).name();
+    Some((input.interner.resolve(name).to_string(), words))
+}
+
 #[cfg(test)]
 mod cache_aligned_tests;
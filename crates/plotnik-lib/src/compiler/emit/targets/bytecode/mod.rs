@@ -31,3 +31,6 @@ pub(in crate::compiler) fn emit(
     let instructions = emit_instructions(nfa, pipeline.layout(), pool)?;
     pipeline.write_module(pool, &tables, &instructions)
 }
+
+#[cfg(test)]
+mod determinism_tests;
@@ -54,7 +54,7 @@ impl<'a> EmitPipeline<'a> {
     ) -> Result<Self, EmitError> {
         let strings = seed_string_table(ir)?;
         let (types, strings) = build_type_table(schema, strings)?;
-        let layout = compute_layout(ir)?;
+        let layout = compute_layout(input, ir)?;
         Ok(Self {
             input,
             ir,
@@ -218,7 +218,10 @@ impl<'a> EmitPipeline<'a> {
         let instruction_word_count = checked_count(
             self.layout.total_words() as usize,
             EmitError::MAX_INSTRUCTION_WORDS,
-            EmitError::TooManyInstructionWords,
+            |words| EmitError::TooManyInstructionWords {
+                words,
+                largest: None,
+            },
         )?;
         let spans_count = checked_count(
             spans_count(self.ir.spans()),
@@ -34,8 +34,18 @@ pub(in crate::compiler) enum EmitError {
     #[error("too many entry points: {0} (max {max})", max = EmitError::MAX_ENTRY_POINTS)]
     TooManyEntryPoints(usize),
     /// Too many instruction words (exceeds u16 max).
-    #[error("too many instruction words: {0} (max {max})", max = EmitError::MAX_INSTRUCTION_WORDS)]
-    TooManyInstructionWords(usize),
+    ///
+    /// `largest` names the definition whose body contributes the most words,
+    /// so the message points at what to split.
+    #[error(
+        "too many instruction words: {words} (max {max}){culprit}",
+        max = EmitError::MAX_INSTRUCTION_WORDS,
+        culprit = largest_contributor(.largest)
+    )]
+    TooManyInstructionWords {
+        words: usize,
+        largest: Option<(String, usize)>,
+    },
     /// Too many regexes (exceeds u16 max).
     #[error("too many regexes: {0} (max {max})", max = EmitError::MAX_REGEXES)]
     TooManyRegexes(usize),
@@ -67,6 +77,13 @@ impl From<ResultSchemaError> for EmitError {
     }
 }
 
+fn largest_contributor(largest: &Option<(String, usize)>) -> String {
+    match largest {
+        Some((name, words)) => format!("; largest definition `{name}` contributes {words} words"),
+        None => String::new(),
+    }
+}
+
 impl EmitError {
     pub(in crate::compiler) const MAX_STRINGS: usize = 65_534;
     pub(in crate::compiler) const MAX_TYPES: usize = u16::MAX as usize;
@@ -18,9 +18,18 @@ use crate::compiler::{SourceMap, SourcePath};
 /// return the emission result.
 #[track_caller]
 fn try_emit(src: &str) -> Result<Vec<u8>, EmitError> {
+    try_emit_with(src, |builder| builder)
+}
+
+/// [`try_emit`] with the builder's analysis limits adjusted first.
+#[track_caller]
+fn try_emit_with(
+    src: &str,
+    configure: impl FnOnce(QueryBuilder) -> QueryBuilder,
+) -> Result<Vec<u8>, EmitError> {
     let mut source_map = SourceMap::new();
     source_map.add_file(SourcePath::new("query.ptk"), src);
-    let query = QueryBuilder::new(source_map)
+    let query = configure(QueryBuilder::new(source_map))
         .bind(grammar())
         .expect("query parses");
     assert!(query.is_valid(), "query should bind to the grammar:\n{src}");
@@ -135,6 +144,46 @@ fn effect_member_payload_overflow_is_emit_error() {
     );
 }
 
+#[test]
+fn instruction_word_overflow_names_the_largest_definition() {
+    // A child with one nested node is about three words, so the module crosses the
+    // u16 word-address space by repeating one modest body across many definitions,
+    // with `Big` the widest. Each sibling list stays narrow, but their sum still
+    // outgrows the default satisfiability budget, so the test raises it.
+    let body = |width: usize| {
+        let mut body = String::from("(program");
+        for _ in 0..width {
+            body.push_str(" (expression_statement (identifier))");
+        }
+        body.push(')');
+        body
+    };
+    let mut query = String::new();
+    for def in 0..110 {
+        writeln!(query, "D{def} = {}", body(200)).unwrap();
+    }
+    write!(query, "Big = {}", body(250)).unwrap();
+
+    let err = try_emit_with(&query, |builder| {
+        builder.with_satisfiability_work_budget(u64::MAX)
+    })
+    .expect_err("> u16::MAX instruction words must not encode");
+    let EmitError::TooManyInstructionWords {
+        largest: Some((name, words)),
+        ..
+    } = &err
+    else {
+        panic!("got {err:?}");
+    };
+    assert_eq!(name, "Big");
+    assert!(*words >= 250, "got {words} words");
+    assert!(
+        err.to_string()
+            .contains("largest definition `Big` contributes"),
+        "got {err}"
+    );
+}
+
 #[test]
 fn truncated_or_corrupted_module_is_rejected() {
     let bytes = try_emit("Q = (program (_) @name)").expect("valid query emits");
@@ -0,0 +1,5 @@
+//! JSON Schema (draft 2020-12) target for decoded query results.
+
+mod types;
+
+pub(crate) use types::emit_schema;
@@ -0,0 +1,190 @@
+//! JSON Schema documents rendered from the same result items as TypeScript.
+//!
+//! Each public result item becomes a `$defs` entry, so recursive definitions
+//! stay finite. The schema describes the canonical JSON encoding of a result:
+//! records carry every field (absent values are `null`) and variants are
+//! `{ "$tag": ..., "$data": ... }` objects.
+
+use std::collections::HashSet;
+
+use serde_json::{Map, Value, json};
+
+use crate::compiler::analyze::result::{ResultItem, ResultItemKind, ResultSchema};
+use crate::compiler::analyze::types::type_shape::{ListMinimum, TypeId, TypeShape};
+use crate::core::Symbol;
+
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Render the schema document; `root` names the entry point the document
+/// itself validates. Without a root, the document only carries `$defs`.
+pub(crate) fn emit_schema(schema: &ResultSchema<'_>, root: Option<&str>) -> Value {
+    SchemaEmitter::new(schema).emit(root)
+}
+
+struct SchemaEmitter<'a> {
+    schema: &'a ResultSchema<'a>,
+    item_names: HashSet<Symbol>,
+    needs_node_type: bool,
+}
+
+impl<'a> SchemaEmitter<'a> {
+    fn new(schema: &'a ResultSchema<'a>) -> Self {
+        let item_names = schema
+            .entry_point_items()
+            .iter()
+            .map(|item| item.name)
+            .collect();
+        Self {
+            schema,
+            item_names,
+            needs_node_type: false,
+        }
+    }
+
+    fn emit(mut self, root: Option<&str>) -> Value {
+        let mut defs = Map::new();
+        for &item in self.schema.entry_point_items() {
+            let name = self.name(item.name);
+            if defs.contains_key(&name) {
+                continue;
+            }
+            let body = self.render_item(item);
+            defs.insert(name, body);
+        }
+        if self.needs_node_type {
+            defs.insert("Node".to_string(), node_schema());
+        }
+
+        let mut document = Map::new();
+        document.insert("$schema".to_string(), json!(DIALECT));
+        if let Some(root) = root {
+            document.insert("$ref".to_string(), json!(def_ref(root)));
+        }
+        document.insert("$defs".to_string(), Value::Object(defs));
+        Value::Object(document)
+    }
+
+    fn render_item(&mut self, item: ResultItem) -> Value {
+        match item.kind {
+            ResultItemKind::Record | ResultItemKind::Variant | ResultItemKind::Alias => {
+                self.render_shape(item.value_type())
+            }
+            ResultItemKind::MatchOnlyDef => json!({ "type": "null" }),
+        }
+    }
+
+    fn render_ty(&mut self, ty: TypeId) -> Value {
+        if !ty.is_builtin()
+            && let Some(symbol) = self.schema.types.type_name_of(ty)
+            && self.item_names.contains(&symbol)
+        {
+            return json!({ "$ref": def_ref(&self.name(symbol)) });
+        }
+        self.render_shape(ty)
+    }
+
+    fn render_shape(&mut self, ty: TypeId) -> Value {
+        match self.schema.types.expect_type_shape(ty) {
+            TypeShape::Node => self.node_ref(),
+            TypeShape::Text => json!({ "type": "string" }),
+            TypeShape::Bool => json!({ "type": "boolean" }),
+            TypeShape::Option(inner) => {
+                json!({ "anyOf": [self.render_ty(*inner), { "type": "null" }] })
+            }
+            TypeShape::List { element, minimum } => {
+                let mut out = json!({ "type": "array", "items": self.render_ty(*element) });
+                if *minimum == ListMinimum::One {
+                    out["minItems"] = json!(1);
+                }
+                out
+            }
+            TypeShape::Ref(declaration) => {
+                if self.schema.types.declaration_body(*declaration).is_none() {
+                    return self.node_ref();
+                }
+                let name = self.schema.types.declaration_name(*declaration);
+                json!({ "$ref": def_ref(&self.name(name)) })
+            }
+            TypeShape::Record(_) => self.render_record(ty),
+            TypeShape::Variant(_) => self.render_variant(ty),
+        }
+    }
+
+    fn render_record(&mut self, ty: TypeId) -> Value {
+        let mut properties = Map::new();
+        for (name, info, _) in self.schema.record_members(ty) {
+            let value = self.render_ty(info.final_type);
+            properties.insert(self.name(name), value);
+        }
+        let required: Vec<_> = properties.keys().cloned().collect();
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+
+    fn render_variant(&mut self, ty: TypeId) -> Value {
+        let cases: Vec<_> = self
+            .schema
+            .variant_members(ty)
+            .into_iter()
+            .map(|(name, payload, _)| {
+                let tag = self.name(name);
+                let Some(payload) = payload.type_id() else {
+                    return json!({
+                        "type": "object",
+                        "properties": { "$tag": { "const": tag } },
+                        "required": ["$tag"],
+                        "additionalProperties": false,
+                    });
+                };
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "$tag": { "const": tag },
+                        "$data": self.render_record(payload),
+                    },
+                    "required": ["$tag", "$data"],
+                    "additionalProperties": false,
+                })
+            })
+            .collect();
+        json!({ "oneOf": cases })
+    }
+
+    fn node_ref(&mut self) -> Value {
+        self.needs_node_type = true;
+        json!({ "$ref": def_ref("Node") })
+    }
+
+    fn name(&self, symbol: Symbol) -> String {
+        self.schema.interner.resolve(symbol).to_string()
+    }
+}
+
+fn def_ref(name: &str) -> String {
+    format!("#/$defs/{name}")
+}
+
+fn node_schema() -> Value {
+    json!({
+        "description": "A matched syntax node: its kind, source text, and byte span.",
+        "type": "object",
+        "properties": {
+            "kind": { "type": "string" },
+            "text": { "type": "string" },
+            "span": {
+                "type": "array",
+                "prefixItems": [
+                    { "type": "integer", "minimum": 0 },
+                    { "type": "integer", "minimum": 0 },
+                ],
+                "items": false,
+            },
+        },
+        "required": ["kind", "span", "text"],
+        "additionalProperties": false,
+    })
+}
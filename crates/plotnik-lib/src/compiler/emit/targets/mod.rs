@@ -1,3 +1,5 @@
 pub(crate) mod bytecode;
+pub(crate) mod json_schema;
+pub(crate) mod python;
 pub(crate) mod rust;
 pub(crate) mod typescript;
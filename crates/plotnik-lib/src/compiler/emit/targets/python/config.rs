@@ -0,0 +1,18 @@
+//! Configuration types for Python emission.
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Config {
+    pub(crate) dataclasses: bool,
+}
+
+impl Config {
+    /// Create a new Config with default values.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn dataclasses(mut self, value: bool) -> Self {
+        self.dataclasses = value;
+        self
+    }
+}
@@ -0,0 +1,7 @@
+//! Python type declarations for decoded query results.
+
+mod config;
+mod types;
+
+pub(crate) use config::Config;
+pub(crate) use types::emit_schema;
@@ -0,0 +1,300 @@
+//! Python declarations rendered from the same result items as TypeScript.
+//!
+//! Records become `TypedDict`s (or `@dataclass`es) and variants a `Union` of
+//! one class per case. Python has no anonymous record type, so a variant
+//! payload gets a class named after its case, and any other unnamed composite
+//! one named after the field that holds it.
+//!
+//! Aliases are written as quoted `TypeAlias`es and annotations are postponed,
+//! so declarations may reference each other in any order.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+use crate::compiler::analyze::result::{ResultItem, ResultItemKind, ResultSchema};
+use crate::compiler::analyze::types::type_shape::{TypeId, TypeShape};
+use crate::core::Symbol;
+use crate::core::utils::to_pascal_case;
+
+use super::config::Config;
+
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+pub(crate) fn emit_schema(schema: &ResultSchema<'_>, config: Config) -> String {
+    SchemaEmitter::new(schema, config).emit()
+}
+
+struct SchemaEmitter<'a> {
+    schema: &'a ResultSchema<'a>,
+    config: Config,
+    item_names: HashSet<Symbol>,
+    declared_names: HashSet<String>,
+    /// Variant payload class names claimed before their pending declaration.
+    claimed_names: HashSet<String>,
+    /// Unnamed composites referenced by the last declaration, still to declare.
+    pending: Vec<(String, TypeId)>,
+    needs_node_type: bool,
+    out: String,
+}
+
+impl<'a> SchemaEmitter<'a> {
+    fn new(schema: &'a ResultSchema<'a>, config: Config) -> Self {
+        let item_names = schema
+            .entry_point_items()
+            .iter()
+            .map(|item| item.name)
+            .collect();
+        Self {
+            schema,
+            config,
+            item_names,
+            declared_names: HashSet::new(),
+            claimed_names: HashSet::new(),
+            pending: Vec::new(),
+            needs_node_type: false,
+            out: String::new(),
+        }
+    }
+
+    fn emit(mut self) -> String {
+        for &item in self.schema.entry_point_items() {
+            self.emit_item(item);
+            self.emit_pending();
+        }
+
+        let mut output = String::from("from __future__ import annotations\n\n");
+        if self.config.dataclasses {
+            output.push_str("from dataclasses import dataclass\n");
+        }
+        output.push_str("import typing\n\n\n");
+        if self.needs_node_type {
+            output.push_str(&self.node_class());
+        }
+        output.push_str(&self.out);
+        output.truncate(output.trim_end().len());
+        output.push('\n');
+        output
+    }
+
+    fn emit_item(&mut self, item: ResultItem) {
+        let name = self.name(item.name);
+        if !self.declared_names.insert(name.clone()) {
+            return;
+        }
+        match item.kind {
+            ResultItemKind::Record => self.emit_record(&name, item.value_type()),
+            ResultItemKind::Variant => self.emit_variant(&name, item.value_type()),
+            ResultItemKind::Alias => {
+                let body = self.render_shape(item.value_type(), &name);
+                self.emit_alias(&name, &body);
+            }
+            ResultItemKind::MatchOnlyDef => self.emit_alias(&name, "None"),
+        }
+    }
+
+    fn emit_pending(&mut self) {
+        while !self.pending.is_empty() {
+            for (name, ty) in std::mem::take(&mut self.pending) {
+                if !self.declared_names.insert(name.clone()) {
+                    continue;
+                }
+                match self.schema.types.expect_type_shape(ty) {
+                    TypeShape::Record(_) => self.emit_record(&name, ty),
+                    TypeShape::Variant(_) => self.emit_variant(&name, ty),
+                    shape => unreachable!("only composites are declared on demand: {shape:?}"),
+                }
+            }
+        }
+    }
+
+    fn emit_alias(&mut self, name: &str, body: &str) {
+        let _ = write!(self.out, "{name}: typing.TypeAlias = \"{body}\"\n\n\n");
+    }
+
+    fn emit_record(&mut self, name: &str, ty: TypeId) {
+        let mut fields = self
+            .schema
+            .record_members(ty)
+            .into_iter()
+            .map(|(field, info, _)| {
+                let field = self.name(field);
+                let context = format!("{name}{}", to_pascal_case(&field));
+                let value = self.render_ty(info.final_type, &context);
+                (field, value)
+            })
+            .collect::<Vec<_>>();
+        fields.sort_by(|left, right| left.0.cmp(&right.0));
+        self.emit_class(name, &fields);
+    }
+
+    fn emit_variant(&mut self, name: &str, ty: TypeId) {
+        let mut cases = Vec::new();
+        for (case, payload, _) in self.schema.variant_members(ty) {
+            let case = self.name(case);
+            let class = self.claim_case_class(format!("{name}{case}"));
+            let tag = format!("typing.Literal[\"{case}\"]");
+            let mut fields = vec![("$tag".to_string(), tag)];
+            if let Some(payload) = payload.type_id() {
+                let data = self.free_class_name(format!("{class}Data"));
+                self.claimed_names.insert(data.clone());
+                self.pending.push((data.clone(), payload));
+                fields.push(("$data".to_string(), data));
+            }
+            self.emit_class(&class, &fields);
+            cases.push(class);
+        }
+        let body = match cases.as_slice() {
+            [case] => case.clone(),
+            cases => format!("typing.Union[{}]", cases.join(", ")),
+        };
+        self.emit_alias(name, &body);
+    }
+
+    /// Declare the class for one variant case.
+    fn claim_case_class(&mut self, class: String) -> String {
+        let class = self.free_class_name(class);
+        self.declared_names.insert(class.clone());
+        class
+    }
+
+    /// `{Name}{Case}` and `{Name}{Case}Data` can land on a definition or on
+    /// another variant's classes, so a taken spelling gets the first free `_N`
+    /// disambiguator.
+    fn free_class_name(&self, class: String) -> String {
+        if !self.is_taken(&class) {
+            return class;
+        }
+        (1..)
+            .map(|index| format!("{class}_{index}"))
+            .find(|candidate| !self.is_taken(candidate))
+            .expect("some numeric suffix is free")
+    }
+
+    /// Whether `name` is already declared or claimed, belongs to an item
+    /// declared later, or is the builtin `Node` class.
+    fn is_taken(&self, name: &str) -> bool {
+        name == "Node"
+            || self.declared_names.contains(name)
+            || self.claimed_names.contains(name)
+            || self
+                .item_names
+                .iter()
+                .any(|&symbol| self.schema.interner.resolve(symbol) == name)
+    }
+
+    /// One record class. JSON keys that are not Python identifiers force the
+    /// functional `TypedDict` form; a dataclass renames them instead.
+    fn emit_class(&mut self, name: &str, fields: &[(String, String)]) {
+        if !self.config.dataclasses && fields.iter().any(|(field, _)| !is_identifier(field)) {
+            let fields = fields
+                .iter()
+                .map(|(field, value)| format!("\"{field}\": \"{}\"", value.replace('"', "'")))
+                .collect::<Vec<_>>();
+            let _ = write!(
+                self.out,
+                "{name} = typing.TypedDict(\"{name}\", {{{}}})\n\n\n",
+                fields.join(", ")
+            );
+            return;
+        }
+
+        if self.config.dataclasses {
+            let _ = writeln!(self.out, "@dataclass\nclass {name}:");
+        } else {
+            let _ = writeln!(self.out, "class {name}(typing.TypedDict):");
+        }
+        for (field, value) in fields {
+            let field = if self.config.dataclasses {
+                dataclass_field(field)
+            } else {
+                field.clone()
+            };
+            let _ = writeln!(self.out, "    {field}: {value}");
+        }
+        if fields.is_empty() {
+            self.out.push_str("    pass\n");
+        }
+        self.out.push_str("\n\n");
+    }
+
+    fn render_ty(&mut self, ty: TypeId, context: &str) -> String {
+        if !ty.is_builtin()
+            && let Some(symbol) = self.schema.types.type_name_of(ty)
+            && self.item_names.contains(&symbol)
+        {
+            return self.name(symbol);
+        }
+        self.render_shape(ty, context)
+    }
+
+    fn render_shape(&mut self, ty: TypeId, context: &str) -> String {
+        match self.schema.types.expect_type_shape(ty) {
+            TypeShape::Node => self.node_ref(),
+            TypeShape::Text => "str".to_string(),
+            TypeShape::Bool => "bool".to_string(),
+            TypeShape::Option(inner) => {
+                format!("typing.Optional[{}]", self.render_ty(*inner, context))
+            }
+            TypeShape::List { element, .. } => {
+                format!("list[{}]", self.render_ty(*element, context))
+            }
+            TypeShape::Ref(declaration) => {
+                if self.schema.types.declaration_body(*declaration).is_none() {
+                    return self.node_ref();
+                }
+                self.name(self.schema.types.declaration_name(*declaration))
+            }
+            TypeShape::Record(_) | TypeShape::Variant(_) => {
+                let name = self
+                    .schema
+                    .types
+                    .type_name_of(ty)
+                    .map_or_else(|| context.to_string(), |symbol| self.name(symbol));
+                self.pending.push((name.clone(), ty));
+                name
+            }
+        }
+    }
+
+    fn node_ref(&mut self) -> String {
+        self.needs_node_type = true;
+        "Node".to_string()
+    }
+
+    fn node_class(&self) -> String {
+        let doc =
+            "    \"\"\"A matched syntax node: its kind, source text, and byte span.\"\"\"\n\n";
+        let fields = "    kind: str\n    text: str\n    span: list[int]\n\n\n";
+        if self.config.dataclasses {
+            format!("@dataclass\nclass Node:\n{doc}{fields}")
+        } else {
+            format!("class Node(typing.TypedDict):\n{doc}{fields}")
+        }
+    }
+
+    fn name(&self, symbol: Symbol) -> String {
+        self.schema.interner.resolve(symbol).to_string()
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.contains(&name)
+}
+
+/// Dataclass attribute for a JSON key: `$tag` becomes `tag`, and keywords
+/// take a trailing underscore.
+fn dataclass_field(name: &str) -> String {
+    let name = name.trim_start_matches('$');
+    if KEYWORDS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name.to_string()
+    }
+}
@@ -1,5 +1,7 @@
 //! Configuration types for TypeScript emission.
 
+use std::collections::BTreeSet;
+
 use crate::core::Colors;
 
 /// How to represent match-only output in TypeScript.
@@ -18,6 +20,11 @@ pub struct Config {
     pub(crate) emit_node_interface: bool,
     pub(crate) include_points: bool,
     pub(crate) match_only_type: MatchOnlyType,
+    pub(crate) type_prefix: Option<String>,
+    pub(crate) type_suffix: Option<String>,
+    pub(crate) reserved_names: BTreeSet<String>,
+    pub(crate) readonly: bool,
+    pub(crate) validators: bool,
     pub(crate) colors: Colors,
 }
 
@@ -28,6 +35,11 @@ impl Default for Config {
             emit_node_interface: true,
             include_points: false,
             match_only_type: MatchOnlyType::default(),
+            type_prefix: None,
+            type_suffix: None,
+            reserved_names: BTreeSet::new(),
+            readonly: false,
+            validators: false,
             colors: Colors::OFF,
         }
     }
@@ -59,6 +71,31 @@ impl Config {
         self
     }
 
+    pub fn type_prefix(mut self, value: Option<String>) -> Self {
+        self.type_prefix = value;
+        self
+    }
+
+    pub fn type_suffix(mut self, value: Option<String>) -> Self {
+        self.type_suffix = value;
+        self
+    }
+
+    pub fn reserved_names(mut self, value: BTreeSet<String>) -> Self {
+        self.reserved_names = value;
+        self
+    }
+
+    pub fn readonly(mut self, value: bool) -> Self {
+        self.readonly = value;
+        self
+    }
+
+    pub fn validators(mut self, value: bool) -> Self {
+        self.validators = value;
+        self
+    }
+
     pub fn colored(mut self, enabled: bool) -> Self {
         self.colors = Colors::new(enabled);
         self
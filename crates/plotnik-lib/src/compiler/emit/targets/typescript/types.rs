@@ -1,9 +1,9 @@
 //! TypeScript declarations rendered directly from target-neutral output facts.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::compiler::analyze::result::{
-    CaptureMemberKind, CaptureScopeKind, ResultItem, ResultItemKind, ResultSchema,
+    CaptureScopeKind, ResultItem, ResultItemKind, ResultSchema,
 };
 use crate::compiler::analyze::types::type_shape::{
     CasePayload, DefinitionOutput, ListMinimum, RecordField, TypeId, TypeShape,
@@ -43,7 +43,9 @@ struct SchemaEmitter<'a> {
     schema: &'a ResultSchema<'a>,
     config: Config,
     item_names: HashSet<Symbol>,
+    type_names: HashMap<Symbol, String>,
     declared_names: HashSet<String>,
+    declared_items: Vec<(String, ResultItem)>,
     needs_node_type: bool,
     sink: Sink<SemanticTag>,
     map_enabled: bool,
@@ -56,11 +58,14 @@ impl<'a> SchemaEmitter<'a> {
             .iter()
             .map(|item| item.name)
             .collect();
+        let type_names = type_names(schema, &config);
         Self {
             schema,
             config,
             item_names,
+            type_names,
             declared_names: HashSet::new(),
+            declared_items: Vec::new(),
             needs_node_type: false,
             sink: Sink::new(),
             map_enabled: false,
@@ -84,6 +89,9 @@ impl<'a> SchemaEmitter<'a> {
         for item in items {
             self.emit_item(item);
         }
+        if self.config.validators {
+            self.emit_validators();
+        }
 
         let mut output = if self.map_enabled {
             self.sink.plain().to_string()
@@ -126,10 +134,12 @@ impl<'a> SchemaEmitter<'a> {
     }
 
     fn emit_item(&mut self, item: ResultItem) {
-        let name = self.name(item.name);
+        let name = self.type_name(item.name);
         if !self.declared_names.insert(name.clone()) {
             return;
         }
+        self.declared_items.push((name.clone(), item));
+        self.emit_doc_comment(item.name);
         match item.kind {
             ResultItemKind::Record => self.emit_interface(&name, item.value_type()),
             ResultItemKind::Variant => self.emit_variant(&name, item.value_type()),
@@ -147,6 +157,32 @@ impl<'a> SchemaEmitter<'a> {
         }
     }
 
+    /// JSDoc from the comments above the definition that declares `name`.
+    fn emit_doc_comment(&mut self, name: Symbol) {
+        let Some(def_id) = self.schema.definitions.id_for_symbol(name) else {
+            return;
+        };
+        let Some(lines) = self.schema.definitions.definition(def_id).doc_comment() else {
+            return;
+        };
+        self.sink.set_style(Style::Dim);
+        if let [line] = lines.as_slice() {
+            self.sink.push(&format!("/** {} */\n", jsdoc_text(line)));
+        } else {
+            self.sink.push("/**\n");
+            for line in &lines {
+                let line = jsdoc_text(line);
+                if line.is_empty() {
+                    self.sink.push(" *\n");
+                } else {
+                    self.sink.push(&format!(" * {line}\n"));
+                }
+            }
+            self.sink.push(" */\n");
+        }
+        self.sink.reset_style();
+    }
+
     fn emit_type_decl(&mut self, name: &str, output: DefinitionOutput, body: Sink<SemanticTag>) {
         emit_export(&mut self.sink, self.config.export);
         self.sink.styled(Style::Dim, "type");
@@ -178,6 +214,7 @@ impl<'a> SchemaEmitter<'a> {
             let value = self.render_ty(info.final_type);
             self.sink.reset_style();
             self.sink.push("  ");
+            push_readonly_modifier(&mut self.sink, self.config.readonly);
             self.push_mapped(&field, ty, Some(member));
             self.sink.set_style(Style::Dim);
             self.sink.push(":");
@@ -236,7 +273,7 @@ impl<'a> SchemaEmitter<'a> {
             && self.item_names.contains(&symbol)
         {
             let mut out = Sink::new();
-            out.styled(Style::Blue, &self.name(symbol));
+            out.styled(Style::Blue, &self.type_name(symbol));
             return out;
         }
         self.render_shape(ty)
@@ -255,7 +292,7 @@ impl<'a> SchemaEmitter<'a> {
                 }
                 let name = self.schema.types.declaration_name(*declaration);
                 let mut out = Sink::new();
-                out.styled(Style::Blue, &self.name(name));
+                out.styled(Style::Blue, &self.type_name(name));
                 out
             }
             TypeShape::Record(_) => self.inline_record(ty, false),
@@ -272,12 +309,20 @@ impl<'a> SchemaEmitter<'a> {
     }
 
     fn render_array(&self, element: TypeId, minimum: ListMinimum) -> Sink<SemanticTag> {
+        if minimum == ListMinimum::Zero && self.config.readonly {
+            let mut out = Sink::new();
+            out.styled(Style::Dim, "ReadonlyArray<");
+            out.append(self.render_ty(element));
+            out.styled(Style::Dim, ">");
+            return out;
+        }
         if minimum == ListMinimum::Zero {
             let mut out = self.render_ty(element);
             out.styled(Style::Dim, "[]");
             return out;
         }
         let mut out = Sink::new();
+        push_readonly_modifier(&mut out, self.config.readonly);
         out.styled(Style::Dim, "[");
         out.append(self.render_ty(element));
         out.styled(Style::Dim, ", ...");
@@ -304,6 +349,7 @@ impl<'a> SchemaEmitter<'a> {
         out.push(" ");
         let last = fields.len() - 1;
         for (position, (name, info, member)) in fields.into_iter().enumerate() {
+            push_readonly_modifier(&mut out, self.config.readonly);
             if tags {
                 out.tagged(
                     SemanticTag {
@@ -344,50 +390,21 @@ impl<'a> SchemaEmitter<'a> {
     }
 
     fn record_members(&self, ty: TypeId) -> Vec<(String, RecordField, ResultMemberId)> {
-        let scope = self
+        let mut fields = self
             .schema
-            .layout()
-            .scope(ty)
-            .expect("record output has a capture scope");
-        assert_eq!(
-            scope.kind(),
-            CaptureScopeKind::Record,
-            "record output must have a record capture scope"
-        );
-        let mut fields = scope
-            .members()
-            .map(|member| {
-                let descriptor = self.schema.layout().expect_member(member);
-                let CaptureMemberKind::Field(info) = descriptor.kind else {
-                    unreachable!("record capture scope contains only field members");
-                };
-                (self.name(descriptor.name), info, member)
-            })
+            .record_members(ty)
+            .into_iter()
+            .map(|(name, info, member)| (self.name(name), info, member))
             .collect::<Vec<_>>();
         fields.sort_by(|left, right| left.0.cmp(&right.0));
         fields
     }
 
     fn variant_members(&self, ty: TypeId) -> Vec<(String, CasePayload, ResultMemberId)> {
-        let scope = self
-            .schema
-            .layout()
-            .scope(ty)
-            .expect("variant output has a capture scope");
-        assert_eq!(
-            scope.kind(),
-            CaptureScopeKind::Variant,
-            "variant output must have a variant capture scope"
-        );
-        scope
-            .members()
-            .map(|member| {
-                let descriptor = self.schema.layout().expect_member(member);
-                let CaptureMemberKind::Case(payload) = descriptor.kind else {
-                    unreachable!("variant capture scope contains only case members");
-                };
-                (self.name(descriptor.name), payload, member)
-            })
+        self.schema
+            .variant_members(ty)
+            .into_iter()
+            .map(|(name, payload, member)| (self.name(name), payload, member))
             .collect()
     }
 
@@ -400,7 +417,9 @@ impl<'a> SchemaEmitter<'a> {
     ) -> Sink<SemanticTag> {
         let mut out = Sink::new();
         out.styled(Style::Dim, "{");
-        out.push(" $tag");
+        out.push(" ");
+        push_readonly_modifier(&mut out, self.config.readonly);
+        out.push("$tag");
         out.styled(Style::Dim, ":");
         out.push(" ");
         out.set_style(Style::Green);
@@ -418,7 +437,11 @@ impl<'a> SchemaEmitter<'a> {
             return out;
         };
         out.set_style(Style::Dim);
-        out.push("; $data");
+        out.push("; ");
+        out.reset_style();
+        push_readonly_modifier(&mut out, self.config.readonly);
+        out.set_style(Style::Dim);
+        out.push("$data");
         out.set_style(Style::Dim);
         out.push(":");
         out.reset_style();
@@ -503,6 +526,180 @@ impl<'a> SchemaEmitter<'a> {
     fn name(&self, symbol: Symbol) -> String {
         self.schema.interner.resolve(symbol).to_string()
     }
+
+    fn type_name(&self, symbol: Symbol) -> String {
+        self.type_names
+            .get(&symbol)
+            .cloned()
+            .expect("every named type is an emitted item")
+    }
+}
+
+/// Runtime type guards mirroring the declarations.
+///
+/// Each check is one boolean expression over a value expression; the walk
+/// follows `render_ty`/`render_shape`, so a named type is checked by calling
+/// its own guard rather than being expanded again.
+impl SchemaEmitter<'_> {
+    fn emit_validators(&mut self) {
+        self.sink.push(concat!(
+            "function isObject(value: unknown): value is Record<string, unknown> {\n",
+            "  return typeof value === \"object\" && value !== null;\n",
+            "}\n\n",
+        ));
+        if self.needs_node_type {
+            // The guard checks the `Node` shape this config declares, so points are
+            // required exactly when `include_points` adds them to the interface.
+            let points = if self.config.include_points {
+                self.sink.push(concat!(
+                    "function isPoint(value: unknown): boolean {\n",
+                    "  return isObject(value) && typeof value.row === \"number\" && ",
+                    "typeof value.column === \"number\";\n",
+                    "}\n\n",
+                ));
+                " && isPoint(value.startPoint) && isPoint(value.endPoint)"
+            } else {
+                ""
+            };
+            self.sink.push(&format!(
+                "function isNode(value: unknown): value is Node {{\n  return isObject(value) && \
+                 typeof value.kind === \"string\" && typeof value.text === \"string\" && \
+                 Array.isArray(value.span){points};\n}}\n\n"
+            ));
+        }
+        for (name, item) in std::mem::take(&mut self.declared_items) {
+            let check = match item.output {
+                DefinitionOutput::MatchOnly => match self.config.match_only_type {
+                    MatchOnlyType::Undefined => "value === undefined".to_string(),
+                    MatchOnlyType::Null => "value === null".to_string(),
+                },
+                DefinitionOutput::Value(ty) => self.shape_check(ty, "value", 0),
+            };
+            emit_export(&mut self.sink, self.config.export);
+            self.sink.push(&format!(
+                "function is{name}(value: unknown): value is {name} {{\n  return {check};\n}}\n\n"
+            ));
+        }
+    }
+
+    fn type_check(&self, ty: TypeId, value: &str, depth: usize) -> String {
+        if !ty.is_builtin()
+            && let Some(symbol) = self.schema.types.type_name_of(ty)
+            && self.item_names.contains(&symbol)
+        {
+            return format!("is{}({value})", self.type_name(symbol));
+        }
+        self.shape_check(ty, value, depth)
+    }
+
+    fn shape_check(&self, ty: TypeId, value: &str, depth: usize) -> String {
+        match self.schema.types.expect_type_shape(ty) {
+            TypeShape::Node => format!("isNode({value})"),
+            TypeShape::Text => format!("typeof {value} === \"string\""),
+            TypeShape::Bool => format!("typeof {value} === \"boolean\""),
+            TypeShape::Option(inner) => {
+                format!(
+                    "({value} === null || {})",
+                    self.type_check(*inner, value, depth)
+                )
+            }
+            TypeShape::List { element, minimum } => {
+                let element_value = format!("e{depth}");
+                let element_check = self.type_check(*element, &element_value, depth + 1);
+                let non_empty = if *minimum == ListMinimum::Zero {
+                    String::new()
+                } else {
+                    format!("{value}.length > 0 && ")
+                };
+                format!(
+                    "(Array.isArray({value}) && {non_empty}{value}.every(({element_value}) => {element_check}))"
+                )
+            }
+            TypeShape::Ref(declaration) => {
+                if self.schema.types.declaration_body(*declaration).is_none() {
+                    return format!("isNode({value})");
+                }
+                let name = self.schema.types.declaration_name(*declaration);
+                format!("is{}({value})", self.type_name(name))
+            }
+            TypeShape::Record(_) => self.record_check(ty, value, depth),
+            TypeShape::Variant(_) => {
+                let cases = self
+                    .variant_members(ty)
+                    .into_iter()
+                    .map(|(name, payload, _)| {
+                        let tag = format!("{value}.$tag === \"{name}\"");
+                        match payload.type_id() {
+                            Some(payload) => {
+                                let data = format!("{value}.$data");
+                                format!("({tag} && {})", self.record_check(payload, &data, depth))
+                            }
+                            None => tag,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                format!("(isObject({value}) && ({}))", cases.join(" || "))
+            }
+        }
+    }
+
+    fn record_check(&self, ty: TypeId, value: &str, depth: usize) -> String {
+        let mut checks = vec![format!("isObject({value})")];
+        for (name, info, _) in self.record_members(ty) {
+            checks.push(self.type_check(info.final_type, &format!("{value}.{name}"), depth));
+        }
+        format!("({})", checks.join(" && "))
+    }
+}
+
+/// Declared TypeScript name for every emitted item.
+///
+/// Names are claimed in item order against a taken set seeded with the reserved
+/// names and the builtin `Node`. Every item first tries its affixed spelling;
+/// only items that lost that spelling then take the first free `_N`
+/// disambiguator, so renaming one item never displaces another.
+fn type_names(schema: &ResultSchema<'_>, config: &Config) -> HashMap<Symbol, String> {
+    let mut taken: HashSet<String> = config.reserved_names.iter().cloned().collect();
+    taken.insert("Node".to_string());
+
+    let mut names = HashMap::new();
+    let mut collided = Vec::new();
+    for item in schema.entry_point_items() {
+        if names.contains_key(&item.name) || collided.contains(&item.name) {
+            continue;
+        }
+        let name = affixed_type_name(schema, config, item.name);
+        if taken.insert(name.clone()) {
+            names.insert(item.name, name);
+        } else {
+            collided.push(item.name);
+        }
+    }
+    for symbol in collided {
+        let name = affixed_type_name(schema, config, symbol);
+        let disambiguated = (1..)
+            .map(|index| format!("{name}_{index}"))
+            .find(|candidate| taken.insert(candidate.clone()))
+            .expect("some numeric suffix is free");
+        names.insert(symbol, disambiguated);
+    }
+    names
+}
+
+fn affixed_type_name(schema: &ResultSchema<'_>, config: &Config, symbol: Symbol) -> String {
+    format!(
+        "{}{}{}",
+        config.type_prefix.as_deref().unwrap_or_default(),
+        schema.interner.resolve(symbol),
+        config.type_suffix.as_deref().unwrap_or_default(),
+    )
+}
+
+fn push_readonly_modifier(sink: &mut Sink<SemanticTag>, enabled: bool) {
+    if enabled {
+        sink.styled(Style::Dim, "readonly");
+        sink.push(" ");
+    }
 }
 
 fn emit_export(sink: &mut Sink<SemanticTag>, enabled: bool) {
@@ -512,6 +709,11 @@ fn emit_export(sink: &mut Sink<SemanticTag>, enabled: bool) {
     }
 }
 
+/// Comment text cannot close the JSDoc block early.
+fn jsdoc_text(line: &str) -> String {
+    line.replace("*/", "*\\/")
+}
+
 fn emit_node_field(sink: &mut Sink<SemanticTag>, name: &str, ty: Sink<SemanticTag>) {
     sink.reset_style();
     sink.push("  ");
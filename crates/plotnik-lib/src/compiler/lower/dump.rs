@@ -37,6 +37,25 @@ pub(crate) fn dump_nfa(
     out
 }
 
+/// Render the optimized NFA as a Graphviz DOT digraph, label space.
+///
+/// One node per instruction, clustered by the same provenance windows that
+/// head the text dump's `[transitions]` sections. Match successors are plain
+/// edges, numbered by priority when there is more than one; calls draw a bold
+/// edge into the callee and a dashed edge to each return label.
+pub(crate) fn dump_nfa_dot(
+    nfa: &SemanticNfa,
+    artifacts: AnalysisArtifacts<'_>,
+    layout: &CaptureLayout,
+) -> String {
+    let graph = nfa.raw();
+    let dumper = NfaDumper::new(graph, artifacts, layout);
+
+    let mut out = String::new();
+    dumper.dot_graph(&mut out);
+    out
+}
+
 /// Renders instructions in the dump format. Besides the full [`dump_nfa`]
 /// output, codegen borrows this per-instruction: each generated state arm
 /// carries its instruction as a comment in this exact format, so generated
@@ -150,6 +169,145 @@ impl NfaDumper<'_> {
         }
     }
 
+    fn dot_graph(&self, out: &mut String) {
+        out.push_str("digraph nfa {\n");
+        out.push_str("  node [shape=box fontname=\"monospace\"]\n\n");
+
+        let mut entries: Vec<(&str, Label)> = self
+            .graph
+            .entry_points()
+            .iter()
+            .map(|(&def_id, entry)| (self.def_name(def_id), entry.target))
+            .collect();
+        entries.sort_by_key(|(name, _)| *name);
+        for (index, (name, label)) in entries.iter().enumerate() {
+            writeln!(
+                out,
+                "  entry{index} [shape=plaintext label=\"{}\"]",
+                dot_escape(name)
+            )
+            .expect("writing to a String is infallible");
+            writeln!(out, "  entry{index} -> n{}", label.0)
+                .expect("writing to a String is infallible");
+        }
+
+        let mut sorted: Vec<&InstructionIR> = self.graph.instructions().iter().collect();
+        sorted.sort_by_key(|i| i.label());
+
+        let mut current: Option<LabelOrigin> = None;
+        let mut clusters = 0usize;
+        for &instr in &sorted {
+            let origin = self.origin_of(instr.label());
+            if current != Some(origin) {
+                if current.is_some() {
+                    out.push_str("  }\n");
+                }
+                let header = self.origin_header(origin);
+                let header = header.strip_suffix(':').unwrap_or(&header);
+                writeln!(out, "\n  subgraph cluster{clusters} {{")
+                    .expect("writing to a String is infallible");
+                writeln!(out, "    label=\"{}\"", dot_escape(header))
+                    .expect("writing to a String is infallible");
+                clusters += 1;
+                current = Some(origin);
+            }
+            writeln!(
+                out,
+                "    n{} [label=\"{}\"]",
+                instr.label().0,
+                dot_escape(&self.dot_label(instr))
+            )
+            .expect("writing to a String is infallible");
+        }
+        if current.is_some() {
+            out.push_str("  }\n");
+        }
+
+        out.push('\n');
+        for &instr in &sorted {
+            self.dot_edges(instr, out);
+        }
+        out.push_str("}\n");
+    }
+
+    /// Node text: the label, the nav glyph, and the instruction content.
+    fn dot_label(&self, instr: &InstructionIR) -> String {
+        let (symbol, content) = match instr {
+            InstructionIR::Match(m) => (nav_symbol(m.nav), self.match_content(m)),
+            InstructionIR::Call(call) => {
+                let symbol = match call.entry {
+                    CallEntry::CallerOwned { nav, .. } => nav_symbol(nav),
+                    CallEntry::CalleeOwned { .. } => Symbol::EMPTY,
+                };
+                let field_part = match call.field() {
+                    Some(field_id) => format!("{}: ", self.field_name(field_id)),
+                    None => String::new(),
+                };
+                let content = format!("{field_part}({})", self.callee_name(call.target));
+                (symbol, content)
+            }
+            InstructionIR::Return(r) => {
+                let port = r.port.to_byte();
+                let content = if port == 0 {
+                    "▶".to_string()
+                } else {
+                    format!("▶ p{port}")
+                };
+                (Symbol::EMPTY, content)
+            }
+        };
+
+        let mut parts = vec![format!("{:0w$}", instr.label().0, w = self.label_width)];
+        let symbol = symbol.format();
+        if !symbol.trim().is_empty() {
+            parts.push(symbol.trim().to_string());
+        }
+        if !content.is_empty() {
+            parts.push(content);
+        }
+        parts.join(" ")
+    }
+
+    fn dot_edges(&self, instr: &InstructionIR, out: &mut String) {
+        let from = instr.label().0;
+        match instr {
+            InstructionIR::Match(m) => {
+                let numbered = m.successors.len() > 1;
+                for (index, successor) in m.successors.iter().enumerate() {
+                    if numbered {
+                        writeln!(
+                            out,
+                            "  n{from} -> n{} [label=\"{}\"]",
+                            successor.0,
+                            index + 1
+                        )
+                    } else {
+                        writeln!(out, "  n{from} -> n{}", successor.0)
+                    }
+                    .expect("writing to a String is infallible");
+                }
+            }
+            InstructionIR::Call(call) => {
+                writeln!(out, "  n{from} -> n{} [style=bold]", call.target.0)
+                    .expect("writing to a String is infallible");
+                let returns = call.return_labels();
+                for (port, label) in returns.iter().enumerate() {
+                    if returns.len() > 1 {
+                        writeln!(
+                            out,
+                            "  n{from} -> n{} [style=dashed label=\"p{port}\"]",
+                            label.0
+                        )
+                    } else {
+                        writeln!(out, "  n{from} -> n{} [style=dashed]", label.0)
+                    }
+                    .expect("writing to a String is infallible");
+                }
+            }
+            InstructionIR::Return(_) => {}
+        }
+    }
+
     fn origin_of(&self, label: Label) -> LabelOrigin {
         self.graph
             .origin(label)
@@ -412,6 +570,11 @@ impl NfaDumper<'_> {
     }
 }
 
+/// Escape text for a double-quoted DOT string.
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
 fn literal(payload: &EffectArg) -> usize {
     let EffectArg::Literal(value) = payload else {
         unreachable!("span effects carry literal span ids");
@@ -5,6 +5,7 @@
 //! module; the crate root re-exports only the facade-level API.
 
 mod analyze;
+mod complete;
 pub(crate) mod diagnostics;
 mod fmt;
 mod ids;
@@ -13,6 +14,8 @@ mod lower;
 pub(crate) mod parse;
 pub(crate) mod regex;
 
+#[cfg(test)]
+mod complete_tests;
 pub(crate) mod emit;
 pub(crate) mod query;
 #[cfg(test)]
@@ -24,12 +27,16 @@ pub use crate::compiler::diagnostics::{
     DiagnosticBuilder, DiagnosticKind, Diagnostics, Error, QueryResult, Severity, Source, SourceId,
     SourceKind, SourceMap, SourcePath, Span,
 };
+pub use complete::{Completion, completions_at};
 pub use emit::{
     BytecodeConfig, BytecodeInspection, CodegenProvenance, CodegenTarget, Emission,
-    EmitConfigError, EmitTarget, RustCodegenConfig, RustModuleOutput, RustTypesOutput,
-    TypeScriptBinding, TypeScriptCodegenConfig, TypeScriptMatchOnlyType,
-    TypeScriptNodeRepresentation, TypeScriptTypesOutput, journal_fn_name,
+    EmitConfigError, EmitTarget, JsonSchemaConfig, JsonSchemaOutput, PythonCodegenConfig,
+    PythonTypesOutput, RustCodegenConfig, RustModuleOutput, RustTypesOutput, TypeScriptBinding,
+    TypeScriptCodegenConfig, TypeScriptMatchOnlyType, TypeScriptNodeRepresentation,
+    TypeScriptTypesOutput, journal_fn_name,
 };
 pub use fmt::{FormatError, FormatResult, format_query};
 pub use parse::{QueryToken, tokenize};
-pub use query::{CompiledQuery, Query, QueryBuilder};
+pub use query::{
+    CaptureInfo, CompiledQuery, HoverInfo, PassTimings, Query, QueryBuilder, SemanticToken,
+};
@@ -381,6 +381,82 @@ impl Def {
     pub fn body(&self) -> Option<Pattern> {
         self.0.children().find_map(Pattern::cast)
     }
+
+    /// Text of the comments directly above the definition, one entry per line.
+    ///
+    /// The run of comments stops at a blank line. A comment trailing code on
+    /// its line belongs to that code, not to the definition below.
+    pub fn doc_comment(&self) -> Option<Vec<String>> {
+        let mut comments = Vec::new();
+        let mut newlines = 0;
+        let mut token = self.0.first_token()?.prev_token();
+        while let Some(current) = token {
+            match current.kind() {
+                SyntaxKind::Whitespace => {}
+                SyntaxKind::Newline => {
+                    newlines += 1;
+                    if newlines > 1 {
+                        break;
+                    }
+                }
+                SyntaxKind::LineComment | SyntaxKind::BlockComment if starts_line(&current) => {
+                    newlines = 0;
+                    comments.push(current.clone());
+                }
+                _ => break,
+            }
+            token = current.prev_token();
+        }
+
+        let lines: Vec<String> = comments
+            .iter()
+            .rev()
+            .flat_map(|comment| comment_lines(comment.kind(), comment.text()))
+            .collect();
+        let first = lines.iter().position(|line| !line.is_empty())?;
+        let last = lines.iter().rposition(|line| !line.is_empty())?;
+        Some(lines[first..=last].to_vec())
+    }
+}
+
+fn starts_line(token: &SyntaxToken) -> bool {
+    let mut previous = token.prev_token();
+    while let Some(current) = previous {
+        match current.kind() {
+            SyntaxKind::Whitespace => previous = current.prev_token(),
+            kind => return kind == SyntaxKind::Newline,
+        }
+    }
+    true
+}
+
+fn comment_lines(kind: SyntaxKind, text: &str) -> Vec<String> {
+    if kind == SyntaxKind::LineComment {
+        let body = text
+            .strip_prefix("//")
+            .or_else(|| text.strip_prefix(';'))
+            .expect("lexer guarantees comment delimiter");
+        return vec![
+            body.strip_prefix(' ')
+                .unwrap_or(body)
+                .trim_end()
+                .to_string(),
+        ];
+    }
+    let body = text
+        .strip_prefix("/*")
+        .and_then(|text| text.strip_suffix("*/"))
+        .expect("lexer guarantees comment delimiter");
+    body.lines()
+        .map(|line| {
+            let line = line.trim();
+            let line = line.strip_prefix('*').unwrap_or(line);
+            line.strip_prefix(' ')
+                .unwrap_or(line)
+                .trim_end()
+                .to_string()
+        })
+        .collect()
 }
 
 /// The kind argument of a `(MISSING …)` pattern — the token naming the specific
@@ -0,0 +1,171 @@
+//! Owned JSON rendering of the typed AST.
+//!
+//! AST nodes are Rowan views, so they are walked here into plain JSON rather
+//! than serialized directly. The nesting mirrors [`Query::dump_ast`].
+
+use rowan::TextRange;
+use serde_json::{Value, json};
+
+use crate::compiler::parse::ast::{Capture, NodePredicate};
+use crate::compiler::parse::cst::SyntaxToken;
+use crate::compiler::parse::{self as ast, SyntaxKind, SyntaxNode};
+use crate::compiler::query::Query;
+
+impl Query {
+    /// The typed AST of every source as JSON, in source order.
+    ///
+    /// Each node is an object with a `kind` and a half-open byte `span`;
+    /// missing names and values from recovered syntax are `null`, as is the
+    /// `predicate` of a node pattern without one.
+    pub fn ast_json(&self) -> Value {
+        let ast_map = self.ast_map();
+        let sources = self
+            .source_map()
+            .iter()
+            .filter_map(|source| {
+                let root = ast_map.get(&source.id)?;
+                Some(json!({
+                    "source": source.kind.display_name(),
+                    "kind": "Root",
+                    "span": span(root.syntax().text_range()),
+                    "defs": root.defs().map(|def| def_json(&def)).collect::<Vec<_>>(),
+                }))
+            })
+            .collect();
+        Value::Array(sources)
+    }
+}
+
+fn span(range: TextRange) -> [u32; 2] {
+    [range.start().into(), range.end().into()]
+}
+
+fn token_text(token: Option<SyntaxToken>) -> Value {
+    token.map_or(Value::Null, |token| json!(token.text()))
+}
+
+fn def_json(def: &ast::Def) -> Value {
+    json!({
+        "kind": "Def",
+        "span": span(def.syntax().text_range()),
+        "name": token_text(def.name()),
+        "body": def.body().as_ref().map(pattern_json),
+    })
+}
+
+fn pattern_json(pattern: &ast::Pattern) -> Value {
+    let range = pattern.syntax().text_range();
+    match pattern {
+        ast::Pattern::NamedNodePattern(node) => json!({
+            "kind": "NamedNode",
+            "span": span(range),
+            "node_kind": token_text(node.kind_token()),
+            "predicate": node.predicate().as_ref().map(predicate_json),
+            "children": children_json(node.syntax()),
+        }),
+        ast::Pattern::DefRef(reference) => json!({
+            "kind": "Ref",
+            "span": span(range),
+            "name": token_text(reference.name()),
+        }),
+        ast::Pattern::AnonymousNodePattern(node) => json!({
+            "kind": "AnonymousNode",
+            "span": span(range),
+            "value": token_text(node.value()),
+        }),
+        ast::Pattern::NodeWildcard(_) => json!({
+            "kind": "NodeWildcard",
+            "span": span(range),
+        }),
+        ast::Pattern::Alternation(alternation) => json!({
+            "kind": "Alternation",
+            "span": span(range),
+            "alternatives": alternation
+                .alternatives()
+                .map(|alternative| json!({
+                    "kind": "Alternative",
+                    "span": span(alternative.syntax().text_range()),
+                    "label": token_text(alternative.label()),
+                    "body": alternative.body().as_ref().map(pattern_json),
+                }))
+                .collect::<Vec<_>>(),
+        }),
+        ast::Pattern::SeqPattern(seq) => json!({
+            "kind": "Seq",
+            "span": span(range),
+            "children": children_json(seq.syntax()),
+        }),
+        ast::Pattern::CapturedPattern(captured) => json!({
+            "kind": "CapturedPattern",
+            "span": span(range),
+            "inner": captured.inner().as_ref().map(pattern_json),
+            "capture": capture_json(&captured.capture()),
+        }),
+        ast::Pattern::QuantifiedPattern(quantified) => json!({
+            "kind": "QuantifiedPattern",
+            "span": span(range),
+            "operator": token_text(quantified.operator()),
+            "inner": quantified.inner().as_ref().map(pattern_json),
+        }),
+        ast::Pattern::FieldPattern(field) => json!({
+            "kind": "FieldPattern",
+            "span": span(range),
+            "name": token_text(field.name()),
+            "value": field.value().as_ref().map(pattern_json),
+        }),
+    }
+}
+
+/// A regex value keeps its `/` delimiters, which tells it apart from a string.
+fn predicate_json(predicate: &NodePredicate) -> Value {
+    let value = match predicate.regex() {
+        Some(regex) => json!(regex.syntax().text().to_string()),
+        None => token_text(predicate.string_value()),
+    };
+    json!({
+        "kind": "Predicate",
+        "span": span(predicate.syntax().text_range()),
+        "operator": token_text(predicate.operator_token()),
+        "value": value,
+    })
+}
+
+fn capture_json(capture: &Capture) -> Value {
+    json!({
+        "kind": "Capture",
+        "span": span(capture.syntax().text_range()),
+        "name": token_text(capture.name()),
+        "type": token_text(capture.capture_type().and_then(|capture_type| capture_type.name())),
+    })
+}
+
+/// Child patterns of a node or sequence, with anchors and negated fields
+/// kept in place as the text dump shows them.
+fn children_json(node: &SyntaxNode) -> Vec<Value> {
+    node.children()
+        .filter_map(|child| {
+            let range = child.text_range();
+            match child.kind() {
+                SyntaxKind::Anchor => {
+                    let anchor =
+                        ast::Anchor::cast(child).expect("child is an Anchor by the matched kind");
+                    Some(json!({
+                        "kind": "Anchor",
+                        "span": span(range),
+                        "exact": anchor.is_exact(),
+                    }))
+                }
+                SyntaxKind::NegatedField => {
+                    let field = ast::NegatedField::cast(child)
+                        .expect("child is a NegatedField by the matched kind");
+                    Some(json!({
+                        "kind": "NegatedField",
+                        "span": span(range),
+                        "name": token_text(field.name()),
+                    }))
+                }
+                _ => ast::Pattern::cast(child).map(|pattern| pattern_json(&pattern)),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,193 @@
+use crate::compiler::query::Query;
+
+#[test]
+fn nested_captures_mirror_the_ast() {
+    let query =
+        Query::try_from("Main = (call_expression (identifier) @name) @call").expect("query parses");
+
+    let json = serde_json::to_string_pretty(&query.ast_json()).expect("AST JSON serializes");
+
+    insta::assert_snapshot!(json, @r#"
+    [
+      {
+        "defs": [
+          {
+            "body": {
+              "capture": {
+                "kind": "Capture",
+                "name": "@call",
+                "span": [
+                  44,
+                  49
+                ],
+                "type": null
+              },
+              "inner": {
+                "children": [
+                  {
+                    "capture": {
+                      "kind": "Capture",
+                      "name": "@name",
+                      "span": [
+                        37,
+                        42
+                      ],
+                      "type": null
+                    },
+                    "inner": {
+                      "children": [],
+                      "kind": "NamedNode",
+                      "node_kind": "identifier",
+                      "predicate": null,
+                      "span": [
+                        24,
+                        36
+                      ]
+                    },
+                    "kind": "CapturedPattern",
+                    "span": [
+                      24,
+                      42
+                    ]
+                  }
+                ],
+                "kind": "NamedNode",
+                "node_kind": "call_expression",
+                "predicate": null,
+                "span": [
+                  7,
+                  43
+                ]
+              },
+              "kind": "CapturedPattern",
+              "span": [
+                7,
+                49
+              ]
+            },
+            "kind": "Def",
+            "name": "Main",
+            "span": [
+              0,
+              49
+            ]
+          }
+        ],
+        "kind": "Root",
+        "source": "<query>",
+        "span": [
+          0,
+          49
+        ]
+      }
+    ]
+    "#);
+}
+
+#[test]
+fn node_predicates_are_kept() {
+    let query = Query::try_from(r#"Main = [(identifier == "x") (string =~ /^a/)] @value"#)
+        .expect("query parses");
+
+    let json = serde_json::to_string_pretty(&query.ast_json()).expect("AST JSON serializes");
+
+    insta::assert_snapshot!(json, @r#"
+    [
+      {
+        "defs": [
+          {
+            "body": {
+              "capture": {
+                "kind": "Capture",
+                "name": "@value",
+                "span": [
+                  46,
+                  52
+                ],
+                "type": null
+              },
+              "inner": {
+                "alternatives": [
+                  {
+                    "body": {
+                      "children": [],
+                      "kind": "NamedNode",
+                      "node_kind": "identifier",
+                      "predicate": {
+                        "kind": "Predicate",
+                        "operator": "==",
+                        "span": [
+                          20,
+                          26
+                        ],
+                        "value": "x"
+                      },
+                      "span": [
+                        8,
+                        27
+                      ]
+                    },
+                    "kind": "Alternative",
+                    "label": null,
+                    "span": [
+                      8,
+                      27
+                    ]
+                  },
+                  {
+                    "body": {
+                      "children": [],
+                      "kind": "NamedNode",
+                      "node_kind": "string",
+                      "predicate": {
+                        "kind": "Predicate",
+                        "operator": "=~",
+                        "span": [
+                          36,
+                          43
+                        ],
+                        "value": "/^a/"
+                      },
+                      "span": [
+                        28,
+                        44
+                      ]
+                    },
+                    "kind": "Alternative",
+                    "label": null,
+                    "span": [
+                      28,
+                      44
+                    ]
+                  }
+                ],
+                "kind": "Alternation",
+                "span": [
+                  7,
+                  45
+                ]
+              },
+              "kind": "CapturedPattern",
+              "span": [
+                7,
+                52
+              ]
+            },
+            "kind": "Def",
+            "name": "Main",
+            "span": [
+              0,
+              52
+            ]
+          }
+        ],
+        "kind": "Root",
+        "source": "<query>",
+        "span": [
+          0,
+          52
+        ]
+      }
+    ]
+    "#);
+}
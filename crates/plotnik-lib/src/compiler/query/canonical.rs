@@ -0,0 +1,64 @@
+//! Layout-insensitive rendering of a query, for cache keys.
+
+use rowan::{NodeOrToken, WalkEvent};
+
+use crate::compiler::parse::{Def, SyntaxKind};
+use crate::compiler::query::Query;
+
+impl Query {
+    /// The query's tokens in a fixed form, meant only as a cache key.
+    ///
+    /// Every whitespace run becomes one space and comments are dropped, except
+    /// a definition's doc comment, which generated TypeScript carries as
+    /// JSDoc. Queries that differ only in layout or in other comments share a
+    /// key and emit identical artifacts (absent span inspection, which
+    /// records source offsets). Strings and regex literals are kept verbatim.
+    /// Unlike [`format_query`](crate::format_query) the result is not meant to
+    /// be read, and definition order is kept since it decides entry points.
+    pub fn canonical_form(&self) -> String {
+        let ast_map = self.ast_map();
+        let mut out = String::new();
+        for source in self.source_map().iter() {
+            let Some(root) = ast_map.get(&source.id) else {
+                continue;
+            };
+            let mut separate = false;
+            let mut events = root.syntax().preorder_with_tokens();
+            while let Some(event) = events.next() {
+                let WalkEvent::Enter(element) = event else {
+                    continue;
+                };
+                let text = match &element {
+                    NodeOrToken::Node(node)
+                        if matches!(node.kind(), SyntaxKind::Str | SyntaxKind::Regex) =>
+                    {
+                        events.skip_subtree();
+                        node.text().to_string()
+                    }
+                    NodeOrToken::Node(node) => {
+                        let doc = Def::cast(node.clone()).and_then(|def| def.doc_comment());
+                        for line in doc.into_iter().flatten() {
+                            if separate {
+                                out.push(' ');
+                            }
+                            out.push_str("//");
+                            out.push_str(&line);
+                            out.push('\n');
+                            separate = false;
+                        }
+                        continue;
+                    }
+                    NodeOrToken::Token(token) if token.kind().is_trivia() => continue,
+                    NodeOrToken::Token(token) => token.text().to_string(),
+                };
+                if separate {
+                    out.push(' ');
+                }
+                out.push_str(&text);
+                separate = true;
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
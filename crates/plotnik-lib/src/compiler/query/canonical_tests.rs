@@ -0,0 +1,80 @@
+use indoc::indoc;
+
+use crate::compiler::query::Query;
+use crate::compiler::test_utils::synthetic_grammar as grammar;
+use crate::compiler::{BytecodeConfig, QueryBuilder};
+
+const COMPACT: &str = "Decl = (program (lexical_declaration) @decl (identifier)* @ids)";
+
+const SPREAD: &str = indoc! {"
+    // Declarations and the names after them.
+
+    Decl = (program
+        (lexical_declaration)   @decl
+        /* names */ (identifier)* @ids
+    )
+"};
+
+fn canonical(text: &str) -> String {
+    Query::try_from(text)
+        .expect("query parses")
+        .canonical_form()
+}
+
+fn bytecode(text: &str) -> Vec<u8> {
+    QueryBuilder::from_inline(text)
+        .compile(grammar())
+        .expect("query compiles")
+        .emit(BytecodeConfig::new())
+        .expect("bytecode emission answers")
+        .into_artifact()
+        .expect("query emits a module")
+        .bytes()
+        .to_vec()
+}
+
+#[test]
+fn layout_and_comments_do_not_change_the_canonical_form() {
+    assert_eq!(canonical(COMPACT), canonical(SPREAD));
+    assert_eq!(bytecode(COMPACT), bytecode(SPREAD));
+}
+
+#[test]
+fn structural_changes_change_the_canonical_form() {
+    let one_or_more = "Decl = (program (lexical_declaration) @decl (identifier)+ @ids)";
+
+    assert_ne!(canonical(COMPACT), canonical(one_or_more));
+}
+
+#[test]
+fn string_contents_are_kept_exactly() {
+    assert_ne!(
+        canonical(r#"Q = (program "a b" @x)"#),
+        canonical(r#"Q = (program "a  b" @x)"#)
+    );
+}
+
+#[test]
+fn doc_comments_change_the_canonical_form() {
+    let documented = indoc! {"
+        // A declaration followed by names.
+        Decl = (program (lexical_declaration) @decl (identifier)* @ids)
+    "};
+
+    assert_ne!(canonical(COMPACT), canonical(documented));
+}
+
+#[test]
+fn unterminated_string_at_end_of_input_is_kept_verbatim() {
+    let form = canonical("Q = (program \"c  d");
+
+    assert!(form.ends_with("\"c  d\n"), "{form:?}");
+}
+
+#[test]
+fn unterminated_string_keeps_later_tokens_apart() {
+    let spaced = canonical("Q = (program \"c d\nR = (program (a b))");
+    let joined = canonical("Q = (program \"c d\nR = (program (ab))");
+
+    assert_ne!(spaced, joined);
+}
@@ -0,0 +1,88 @@
+//! Every capture in an analyzed query, with its inferred type.
+
+use crate::compiler::analyze::types::type_shape::PatternFlow;
+use crate::compiler::diagnostics::SourceId;
+use crate::compiler::parse::ast::{CapturedPattern, Pattern};
+use crate::compiler::query::Query;
+
+use super::stages::Analysis;
+
+/// A named capture and the field it contributes to its definition's result.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CaptureInfo {
+    /// Capture name without the `@`.
+    pub name: String,
+    /// Inferred field type, rendered the way diagnostics show types.
+    pub type_description: String,
+    /// Definition whose body contains the capture.
+    pub definition: String,
+    /// Source holding the capture.
+    pub source: SourceId,
+    /// Half-open byte span of the capture token in its source.
+    pub span: (u32, u32),
+}
+
+impl Query {
+    /// Every named capture, by definition in declaration order and then in
+    /// source order.
+    ///
+    /// Each capture reports the field type it produces where it is written,
+    /// as [`hover_at`](Self::hover_at) does: one inside an alternation branch
+    /// carries its branch-local type, before an untagged alternation's merge
+    /// makes it optional. Discards are omitted, and the list is empty when
+    /// analysis stopped before types were inferred.
+    pub fn captures(&self) -> Vec<CaptureInfo> {
+        let Some(analysis) = self.analysis() else {
+            return Vec::new();
+        };
+        let graph = &analysis.definitions;
+
+        let mut captures = Vec::new();
+        for &def_id in graph.ids_in_declaration_order() {
+            let definition = graph.definition(def_id);
+            let definition_name = analysis.interner.resolve(definition.name());
+            for captured in definition
+                .body()
+                .syntax()
+                .descendants()
+                .filter_map(CapturedPattern::cast)
+            {
+                let Some(token) = captured.capture().name() else {
+                    continue;
+                };
+                let Some(type_description) = capture_type(analysis, &captured) else {
+                    continue;
+                };
+                let range = token.text_range();
+                captures.push(CaptureInfo {
+                    name: token.text().trim_start_matches('@').to_string(),
+                    type_description,
+                    definition: definition_name.to_string(),
+                    source: definition.source(),
+                    span: (range.start().into(), range.end().into()),
+                });
+            }
+        }
+        captures
+    }
+}
+
+/// The rendered type of the field `captured` contributes. `None` for a
+/// discard, or when the capture's record was not inferred.
+pub(super) fn capture_type(analysis: &Analysis, captured: &CapturedPattern) -> Option<String> {
+    let capture = captured.capture();
+    if capture.is_discard() {
+        return None;
+    }
+    let token = capture.name()?;
+    let name = token.text().trim_start_matches('@');
+    let types = &analysis.type_analysis;
+    let Some(PatternFlow::Fields(record)) =
+        types.pattern_flow(&Pattern::CapturedPattern(captured.clone()))
+    else {
+        return None;
+    };
+    let field = analysis.interner.get(name)?;
+    let field_type = types.expect_record_fields(*record).get(&field)?.final_type;
+    Some(types.describe_type(&analysis.interner, field_type))
+}
@@ -0,0 +1,32 @@
+use std::fmt::Write as _;
+
+use crate::compiler::query::Query;
+
+#[test]
+fn lists_captures_by_definition_with_types_and_spans() {
+    let query = Query::try_from(indoc::indoc! {"
+        Decl = (program (lexical_declaration) @decl (identifier)* @ids)
+        Literal = [Num: (number) @n  Str: (string) @s]
+        Either = [(number) @num (string) @_ (string) @str]
+    "})
+    .expect("query parses");
+
+    let mut out = String::new();
+    for capture in query.captures() {
+        let (start, end) = capture.span;
+        let _ = writeln!(
+            out,
+            "{}.{}: {} @ {start}..{end}",
+            capture.definition, capture.name, capture.type_description
+        );
+    }
+
+    insta::assert_snapshot!(out, @r"
+    Decl.decl: Node @ 38..43
+    Decl.ids: Node[] @ 58..62
+    Literal.n: Node @ 89..91
+    Literal.s: Node @ 107..109
+    Either.num: Node @ 130..134
+    Either.str: Node @ 156..160
+    ");
+}
@@ -0,0 +1,36 @@
+//! Editor go-to-definition for references in an analyzed query.
+
+use rowan::TextSize;
+
+use crate::compiler::diagnostics::{SourceId, Span};
+use crate::compiler::parse::ast::DefRef;
+use crate::compiler::query::Query;
+
+impl Query {
+    /// Name span of the definition that the reference at byte `offset` of
+    /// `source` resolves to; the span carries the defining source.
+    ///
+    /// Both name edges count, as for [`hover_at`](Self::hover_at). `None` away
+    /// from a reference, for an unresolved name, or when analysis stopped
+    /// before definitions were collected.
+    pub fn definition_span(&self, source: SourceId, offset: u32) -> Option<Span> {
+        let analysis = self.analysis()?;
+        let graph = &analysis.definitions;
+        let offset = TextSize::from(offset);
+
+        let reference = graph
+            .ids_in_declaration_order()
+            .iter()
+            .map(|&def_id| graph.definition(def_id))
+            .filter(|definition| definition.source() == source)
+            .flat_map(|definition| definition.body().syntax().descendants())
+            .filter_map(DefRef::cast)
+            .find(|reference| {
+                reference
+                    .name()
+                    .is_some_and(|token| token.text_range().contains_inclusive(offset))
+            })?;
+        let target = graph.reference_target(&reference)?;
+        Some(graph.definition(target).name_span())
+    }
+}
@@ -0,0 +1,43 @@
+use super::Query;
+use crate::compiler::diagnostics::SourceId;
+
+fn offset_of(text: &str, needle: &str) -> u32 {
+    text.rfind(needle).expect("needle occurs in query") as u32
+}
+
+#[test]
+fn reference_resolves_to_definition_name() {
+    let text = "Foo = (identifier) @id\nBaz = (expression_statement (Foo) @foo)";
+    let query = Query::try_from(text).expect("query analyzes");
+    assert!(
+        query.is_valid(),
+        "{}",
+        query.diagnostics().render(query.source_map())
+    );
+    let at = offset_of(text, "Foo)") + 1;
+
+    let span = query
+        .definition_span(SourceId::default(), at)
+        .expect("reference resolves");
+
+    assert_eq!(span.source, SourceId::default());
+    assert_eq!(&text[span.range], "Foo");
+    assert_eq!(u32::from(span.range.start()), 0);
+}
+
+#[test]
+fn unresolved_reference_has_no_definition() {
+    let text = "Foo = (identifier) @id\nBaz = (expression_statement (Missing) @m)";
+    let query = Query::try_from(text).expect("query analyzes");
+    let at = offset_of(text, "Missing");
+
+    assert_eq!(query.definition_span(SourceId::default(), at), None);
+}
+
+#[test]
+fn no_definition_away_from_references() {
+    let text = "Foo = (identifier) @id\nBaz = (expression_statement (Foo) @foo)";
+    let query = Query::try_from(text).expect("query analyzes");
+
+    assert_eq!(query.definition_span(SourceId::default(), 1), None);
+}
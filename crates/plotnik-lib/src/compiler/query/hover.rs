@@ -0,0 +1,105 @@
+//! Editor hover for captures and definition references in an analyzed query.
+
+use rowan::TextSize;
+
+use crate::compiler::analyze::types::type_shape::DefinitionOutput;
+use crate::compiler::diagnostics::SourceId;
+use crate::compiler::parse::ast::{CapturedPattern, DefRef};
+use crate::compiler::query::Query;
+
+use super::captures::capture_type;
+use super::stages::Analysis;
+
+/// What the capture or reference under the cursor stands for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HoverInfo {
+    /// Capture name without the `@`, or the referenced definition's name.
+    pub name: String,
+    /// Inferred field type of a capture, or the result type of a referenced
+    /// definition; rendered the way diagnostics show types.
+    pub type_description: String,
+    /// Definition whose body contains the capture or reference.
+    pub definition: String,
+    /// Half-open byte span of the hovered token in its source.
+    pub span: (u32, u32),
+}
+
+impl Query {
+    /// The capture `@name` or definition reference token covering byte
+    /// `offset` of `source`.
+    ///
+    /// Both token edges count, so a cursor right after `@x` still hovers it.
+    /// `None` away from a capture or reference, on a discard, for an
+    /// unresolved reference, or when analysis stopped before types were
+    /// inferred.
+    pub fn hover_at(&self, source: SourceId, offset: u32) -> Option<HoverInfo> {
+        let analysis = self.analysis()?;
+        let graph = &analysis.definitions;
+        let offset = TextSize::from(offset);
+
+        for &def_id in graph.ids_in_declaration_order() {
+            let definition = graph.definition(def_id);
+            if definition.source() != source {
+                continue;
+            }
+            let definition_name = analysis.interner.resolve(definition.name());
+            let body = definition.body().syntax();
+
+            let captured = body
+                .descendants()
+                .filter_map(CapturedPattern::cast)
+                .find(|captured| {
+                    captured
+                        .capture()
+                        .name()
+                        .is_some_and(|token| token.text_range().contains_inclusive(offset))
+                });
+            if let Some(captured) = captured {
+                let token = captured
+                    .capture()
+                    .name()
+                    .expect("matched capture has a name token");
+                let type_description = capture_type(analysis, &captured)?;
+                let range = token.text_range();
+                return Some(HoverInfo {
+                    name: token.text().trim_start_matches('@').to_string(),
+                    type_description,
+                    definition: definition_name.to_string(),
+                    span: (range.start().into(), range.end().into()),
+                });
+            }
+
+            let reference = body
+                .descendants()
+                .filter_map(DefRef::cast)
+                .find(|reference| {
+                    reference
+                        .name()
+                        .is_some_and(|token| token.text_range().contains_inclusive(offset))
+                });
+            if let Some(reference) = reference {
+                let token = reference
+                    .name()
+                    .expect("matched reference has a name token");
+                let range = token.text_range();
+                return Some(HoverInfo {
+                    name: token.text().to_string(),
+                    type_description: reference_type(analysis, &reference)?,
+                    definition: definition_name.to_string(),
+                    span: (range.start().into(), range.end().into()),
+                });
+            }
+        }
+        None
+    }
+}
+
+/// The rendered result type of the definition `reference` resolves to.
+fn reference_type(analysis: &Analysis, reference: &DefRef) -> Option<String> {
+    let target = analysis.definitions.reference_target(reference)?;
+    let types = &analysis.type_analysis;
+    Some(match types.def_output(target)? {
+        DefinitionOutput::MatchOnly => "match only".to_string(),
+        DefinitionOutput::Value(type_id) => types.describe_type(&analysis.interner, type_id),
+    })
+}
@@ -0,0 +1,91 @@
+use indoc::indoc;
+
+use super::Query;
+use crate::compiler::diagnostics::SourceId;
+
+fn analyze(text: &str) -> Query {
+    let query = Query::try_from(text).expect("query analyzes");
+    assert!(
+        query.is_valid(),
+        "{}",
+        query.diagnostics().render(query.source_map())
+    );
+    query
+}
+
+fn offset_of(text: &str, needle: &str) -> u32 {
+    text.find(needle).expect("needle occurs in query") as u32
+}
+
+#[test]
+fn node_capture_hovers_as_node() {
+    let text = "Main = (identifier) @x";
+    let query = analyze(text);
+    let at = offset_of(text, "@x");
+
+    let hover = query
+        .hover_at(SourceId::default(), at)
+        .expect("hover on @x");
+
+    assert_eq!(hover.name, "x");
+    assert_eq!(hover.type_description, "Node");
+    assert_eq!(hover.definition, "Main");
+    assert_eq!(hover.span, (at, at + 2));
+}
+
+#[test]
+fn quantified_capture_hovers_as_list() {
+    let text = "Items = (array (number)* @items)";
+    let query = analyze(text);
+    let at = offset_of(text, "@items") + 3;
+
+    let hover = query
+        .hover_at(SourceId::default(), at)
+        .expect("hover on @items");
+
+    assert_eq!(hover.type_description, "Node[]");
+    assert_eq!(hover.definition, "Items");
+}
+
+#[test]
+fn no_hover_away_from_captures() {
+    let text = "Main = (identifier) @x";
+    let query = analyze(text);
+
+    assert_eq!(query.hover_at(SourceId::default(), 2), None);
+}
+
+#[test]
+fn reference_hovers_as_the_referenced_result() {
+    let text = indoc! {"
+        Pair = (binary_expression left: (_) @left right: (_) @right)
+        Main = (expression_statement (Pair) @pair)
+    "};
+    let query = analyze(text);
+    let at = offset_of(text, "(Pair)") + 1;
+
+    let hover = query
+        .hover_at(SourceId::default(), at)
+        .expect("hover on Pair");
+
+    assert_eq!(hover.name, "Pair");
+    assert_eq!(hover.type_description, "{ left: Node, right: Node }");
+    assert_eq!(hover.definition, "Main");
+    assert_eq!(hover.span, (at, at + 4));
+}
+
+#[test]
+fn match_only_reference_hovers_as_match_only() {
+    let text = indoc! {"
+        Callee = (identifier)
+        Main = (call_expression function: (Callee)) @call
+    "};
+    let query = analyze(text);
+    let at = offset_of(text, "(Callee)") + 1;
+
+    let hover = query
+        .hover_at(SourceId::default(), at)
+        .expect("hover on Callee");
+
+    assert_eq!(hover.type_description, "match only");
+}
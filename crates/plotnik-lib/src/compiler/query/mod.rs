@@ -1,7 +1,38 @@
 //! Query facade for Plotnik compilation.
 
+mod ast_json;
+mod canonical;
+mod captures;
 mod dump;
+mod goto;
+mod hover;
 mod printer;
+mod semantic_tokens;
 mod stages;
+mod timings;
+mod type_report;
 
+#[cfg(test)]
+mod ast_json_tests;
+#[cfg(test)]
+mod canonical_tests;
+#[cfg(test)]
+mod captures_tests;
+#[cfg(test)]
+mod goto_tests;
+#[cfg(test)]
+mod hover_tests;
+#[cfg(test)]
+mod semantic_tokens_tests;
+#[cfg(test)]
+mod stages_tests;
+#[cfg(test)]
+mod timings_tests;
+#[cfg(test)]
+mod type_report_tests;
+
+pub use captures::CaptureInfo;
+pub use hover::HoverInfo;
+pub use semantic_tokens::SemanticToken;
 pub use stages::{CompiledQuery, Query, QueryBuilder};
+pub use timings::PassTimings;
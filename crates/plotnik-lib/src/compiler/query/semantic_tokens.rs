@@ -0,0 +1,100 @@
+//! Semantic token classes for highlighting query source.
+
+use crate::compiler::diagnostics::SourceId;
+use crate::compiler::parse::SyntaxKind;
+use crate::compiler::parse::cst::SyntaxToken;
+use crate::compiler::query::Query;
+
+/// One token of query source, classified by its role in the syntax tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct SemanticToken {
+    /// Stable lowercase class: `comment`, `keyword`, `definition`,
+    /// `node_kind`, `field`, `label`, `capture`, `type`, `string`, `regex`,
+    /// or `operator`.
+    pub kind: &'static str,
+    /// Half-open byte span in the query text.
+    pub span: (u32, u32),
+}
+
+impl Query {
+    /// Classified tokens of `source` in document order.
+    ///
+    /// Unlike [`tokenize`](crate::compiler::tokenize), which sees only the
+    /// lexer, names are told apart by the node they sit in: `(Expr)` is a
+    /// definition while `(expr)` is a node kind. Whitespace and text the parser
+    /// could not place are left out. The recovered tree is used as-is, so a
+    /// query with syntax errors still gets tokens.
+    pub fn semantic_tokens(&self, source: SourceId) -> Vec<SemanticToken> {
+        let Some(root) = self.ast_map().get(&source) else {
+            return Vec::new();
+        };
+        root.syntax()
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+            .filter_map(|token| {
+                let kind = class_name(&token)?;
+                let range = token.text_range();
+                Some(SemanticToken {
+                    kind,
+                    span: (range.start().into(), range.end().into()),
+                })
+            })
+            .collect()
+    }
+}
+
+fn class_name(token: &SyntaxToken) -> Option<&'static str> {
+    let class = match token.kind() {
+        SyntaxKind::LineComment | SyntaxKind::BlockComment | SyntaxKind::Shebang => "comment",
+        SyntaxKind::KwError | SyntaxKind::KwMissing => "keyword",
+        SyntaxKind::Id => match token.parent()?.kind() {
+            SyntaxKind::Def | SyntaxKind::DefRef => "definition",
+            SyntaxKind::NamedNode => "node_kind",
+            SyntaxKind::Field | SyntaxKind::NegatedField => "field",
+            SyntaxKind::Alternative => "label",
+            SyntaxKind::CaptureType => "type",
+            _ => return None,
+        },
+        SyntaxKind::Underscore => "node_kind",
+        SyntaxKind::CaptureToken | SyntaxKind::DiscardToken | SyntaxKind::At => "capture",
+        SyntaxKind::DoubleQuote
+        | SyntaxKind::SingleQuote
+        | SyntaxKind::StringContent
+        | SyntaxKind::UnterminatedString => "string",
+        SyntaxKind::RegexLiteral
+        | SyntaxKind::RegexPredicateMatch
+        | SyntaxKind::RegexPredicateNoMatch => "regex",
+        SyntaxKind::ParenOpen
+        | SyntaxKind::ParenClose
+        | SyntaxKind::BracketOpen
+        | SyntaxKind::BracketClose
+        | SyntaxKind::BraceOpen
+        | SyntaxKind::BraceClose
+        | SyntaxKind::DoubleColon
+        | SyntaxKind::Colon
+        | SyntaxKind::Equals
+        | SyntaxKind::Negation
+        | SyntaxKind::Minus
+        | SyntaxKind::Star
+        | SyntaxKind::Plus
+        | SyntaxKind::Question
+        | SyntaxKind::StarQuestion
+        | SyntaxKind::PlusQuestion
+        | SyntaxKind::QuestionQuestion
+        | SyntaxKind::Slash
+        | SyntaxKind::Hash
+        | SyntaxKind::Comma
+        | SyntaxKind::Pipe
+        | SyntaxKind::DotBang
+        | SyntaxKind::Dot
+        | SyntaxKind::OpEq
+        | SyntaxKind::OpNe
+        | SyntaxKind::OpStartsWith
+        | SyntaxKind::OpEndsWith
+        | SyntaxKind::OpContains
+        | SyntaxKind::OpRegexMatch
+        | SyntaxKind::OpRegexNoMatch => "operator",
+        _ => return None,
+    };
+    Some(class)
+}
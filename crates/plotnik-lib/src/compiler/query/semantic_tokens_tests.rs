@@ -0,0 +1,61 @@
+use indoc::indoc;
+
+use crate::compiler::diagnostics::SourceId;
+use crate::compiler::query::Query;
+
+fn render(text: &str) -> String {
+    let query = Query::try_from(text).expect("query parses");
+    query
+        .semantic_tokens(SourceId::default())
+        .iter()
+        .map(|token| {
+            let (start, end) = token.span;
+            let slice = &text[start as usize..end as usize];
+            format!("{:<10} {start}..{end} {slice:?}\n", token.kind)
+        })
+        .collect()
+}
+
+#[test]
+fn tokens_are_classified_by_syntax_role() {
+    let text = indoc! {r#"
+        // calls
+        Call = (call_expression function: (identifier) @fn :: FnName !type_arguments)
+        Items = [A: (Call) B: "x"]* @items
+    "#};
+
+    insta::assert_snapshot!(render(text), @r#"
+    comment    0..8 "// calls"
+    definition 9..13 "Call"
+    operator   14..15 "="
+    operator   16..17 "("
+    node_kind  17..32 "call_expression"
+    field      33..41 "function"
+    operator   41..42 ":"
+    operator   43..44 "("
+    node_kind  44..54 "identifier"
+    operator   54..55 ")"
+    capture    56..59 "@fn"
+    operator   60..62 "::"
+    type       63..69 "FnName"
+    operator   70..71 "!"
+    field      71..85 "type_arguments"
+    operator   85..86 ")"
+    definition 87..92 "Items"
+    operator   93..94 "="
+    operator   95..96 "["
+    label      96..97 "A"
+    operator   97..98 ":"
+    operator   99..100 "("
+    definition 100..104 "Call"
+    operator   104..105 ")"
+    label      106..107 "B"
+    operator   107..108 ":"
+    string     109..110 "\""
+    string     110..111 "x"
+    string     111..112 "\""
+    operator   112..113 "]"
+    operator   113..114 "*"
+    capture    115..121 "@items"
+    "#);
+}
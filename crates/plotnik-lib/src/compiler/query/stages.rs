@@ -10,14 +10,18 @@ use crate::compiler::analyze::result::{ResultModel, ResultSchema};
 use crate::compiler::analyze::shape::PatternFacts;
 use crate::compiler::analyze::shape::validation::{
     AnchorValidationInput, ShapeValidationInput, validate_anchors, validate_ast,
+    validate_ast_provisionally,
 };
-use crate::compiler::analyze::types::check_entry_points;
 use crate::compiler::analyze::types::type_check::{self, TypeAnalysis};
+use crate::compiler::analyze::types::{
+    check_entry_points, check_unused_captures, check_unused_definitions,
+};
 #[cfg(test)]
 use crate::compiler::emit::targets::bytecode::tables::EmitError;
 use crate::compiler::emit::{
-    BytecodeConfig, CodegenProvenance, Emission, EmitTarget, RustCodegenConfig, RustModuleOutput,
-    RustTypesOutput, TypeScriptCodegenConfig, TypeScriptTypesOutput,
+    BytecodeConfig, CodegenProvenance, Emission, EmitTarget, JsonSchemaConfig, JsonSchemaOutput,
+    PythonCodegenConfig, PythonTypesOutput, RustCodegenConfig, RustModuleOutput, RustTypesOutput,
+    TypeScriptCodegenConfig, TypeScriptTypesOutput,
 };
 use crate::compiler::limits::CompilerLimits;
 use crate::compiler::lower::ir::SemanticNfa;
@@ -32,12 +36,20 @@ use crate::compiler::Diagnostics;
 use crate::compiler::diagnostics::{DiagnosticKind, Span};
 use crate::compiler::source::{SourceId, SourceMap};
 
+use super::timings::{PassTimer, PassTimings};
+
 pub(crate) type AstMap = IndexMap<SourceId, Root>;
 
+/// Sources and compiler settings for one query.
+///
+/// To bind one query against several grammars, [`analyze`](Self::analyze) it
+/// once and [`link`](Query::link) the result per grammar.
 pub struct QueryBuilder {
     source_map: SourceMap,
     limits: CompilerLimits,
     strict_lints: bool,
+    analyze_on_error: bool,
+    timing: bool,
 }
 
 impl QueryBuilder {
@@ -46,6 +58,8 @@ impl QueryBuilder {
             source_map,
             limits: CompilerLimits::default(),
             strict_lints: false,
+            analyze_on_error: false,
+            timing: false,
         }
     }
 
@@ -92,12 +106,29 @@ impl QueryBuilder {
         self
     }
 
+    /// Keep analyzing past syntax and validation errors, for editors.
+    ///
+    /// Definitions an error falls in are left out; the others are analyzed so
+    /// types, hovers, and captures stay available while the user types. The
+    /// result is [provisional](Query::is_provisional) and never compiles.
+    pub fn with_analyze_on_error(mut self, enabled: bool) -> Self {
+        self.analyze_on_error = enabled;
+        self
+    }
+
+    /// Record how long each compiler pass takes, for profiling. Read the
+    /// result from [`Query::timings`] or [`CompiledQuery::timings`].
+    pub fn with_timing(mut self, enabled: bool) -> Self {
+        self.timing = enabled;
+        self
+    }
+
     pub fn analyze(self) -> crate::compiler::QueryResult<Query> {
         self.parse()?.analyze()
     }
 
     pub fn compile(self, grammar: &Grammar) -> crate::compiler::QueryResult<CompiledQuery> {
-        self.bind(grammar)?.compile()
+        Ok(self.bind(grammar)?.compile())
     }
 
     pub(crate) fn bind(self, grammar: &Grammar) -> crate::compiler::QueryResult<BindOutcome> {
@@ -107,6 +138,8 @@ impl QueryBuilder {
     pub(crate) fn parse(self) -> crate::compiler::QueryResult<QueryParsed> {
         let mut ast = IndexMap::new();
         let mut diag = Diagnostics::new();
+        let mut timer = PassTimer::new(self.timing);
+        let started = timer.start();
 
         for source in self.source_map.iter() {
             let root = parse_lossless(
@@ -117,6 +150,7 @@ impl QueryBuilder {
             )?;
             ast.insert(source.id, root);
         }
+        timer.record("parse", started);
 
         Ok(QueryParsed {
             source_map: self.source_map,
@@ -124,32 +158,53 @@ impl QueryBuilder {
             ast_map: ast,
             limits: self.limits,
             strict_lints: self.strict_lints,
+            analyze_on_error: self.analyze_on_error,
+            provisional: false,
+            timer,
         })
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct QueryParsed {
     source_map: SourceMap,
     ast_map: AstMap,
     diag: Diagnostics,
     limits: CompilerLimits,
     strict_lints: bool,
+    analyze_on_error: bool,
+    /// Analysis ran over an AST with errors (see
+    /// [`QueryBuilder::with_analyze_on_error`]).
+    provisional: bool,
+    timer: PassTimer,
 }
 
 impl QueryParsed {
     pub(crate) fn analyze(mut self) -> crate::compiler::QueryResult<Query> {
-        let Some(validated) = validate_ast(ShapeValidationInput {
+        let started = self.timer.start();
+        let input = ShapeValidationInput {
             source_map: &self.source_map,
             ast_map: &self.ast_map,
             diag: &mut self.diag,
-        }) else {
+        };
+        let validated = if self.analyze_on_error {
+            let validated = validate_ast_provisionally(input);
+            self.provisional = self.diag.has_errors();
+            Some(validated)
+        } else {
+            validate_ast(input)
+        };
+        self.timer.record("validate", started);
+        let Some(validated) = validated else {
             return Ok(Query::parsed_only(self));
         };
 
+        let started = self.timer.start();
         let mut interner = Interner::new();
         let collected_definitions = resolve_names(&validated, &mut self.diag);
+        self.timer.record("names", started);
 
+        let started = self.timer.start();
         // A flat reference chain can recurse as deeply as a nested source tree, so it gets
         // an explicit stack-depth ceiling and the same fatal recursion-limit outcome.
         let definitions = build_definition_graph(
@@ -164,15 +219,21 @@ impl QueryParsed {
             diag: &mut self.diag,
         });
         if !anchors_valid {
+            self.timer.record("dependencies", started);
             return Ok(Query::parsed_only(self));
         }
         validate_recursion(&definitions, &interner, &mut self.diag);
+        self.timer.record("dependencies", started);
 
+        let started = self.timer.start();
         let type_analysis =
             type_check::infer_types(&mut interner, &definitions, &pattern_facts, &mut self.diag);
         if !self.diag.has_errors() {
             check_entry_points(validated.ast_map(), &interner, &definitions, &mut self.diag);
+            check_unused_captures(&interner, &definitions, &pattern_facts, &mut self.diag);
+            check_unused_definitions(&interner, &definitions, &pattern_facts, &mut self.diag);
         }
+        self.timer.record("types", started);
 
         let analysis = Analysis {
             interner,
@@ -215,6 +276,7 @@ pub(crate) struct AnalyzedQuery {
     analysis: Analysis,
 }
 
+#[derive(Clone)]
 pub(super) struct Analysis {
     pub(super) interner: Interner,
     pub(super) definitions: DefinitionGraph,
@@ -246,6 +308,18 @@ impl Query {
         self.analysis.is_some() && !self.parsed.diag.has_errors()
     }
 
+    /// Whether analysis ran over a source with errors, leaving out the
+    /// definitions they fall in. Only [`QueryBuilder::with_analyze_on_error`]
+    /// produces such a query.
+    pub fn is_provisional(&self) -> bool {
+        self.analysis.is_some() && self.parsed.provisional
+    }
+
+    /// Per-pass durations, when [`QueryBuilder::with_timing`] was enabled.
+    pub fn timings(&self) -> Option<&PassTimings> {
+        self.parsed.timer.timings()
+    }
+
     pub(super) fn analysis(&self) -> Option<&Analysis> {
         self.analysis.as_ref()
     }
@@ -266,6 +340,57 @@ impl Query {
         self.parsed.definition_names()
     }
 
+    /// Caller → callee edges between definitions.
+    ///
+    /// Callers follow declaration order and each caller's callees follow first
+    /// reference. Empty when analysis stopped early.
+    pub fn dependency_edges(&self) -> Vec<(String, String)> {
+        let Some(analysis) = self.analysis() else {
+            return Vec::new();
+        };
+        let graph = &analysis.definitions;
+        let name = |id| analysis.interner.resolve(graph.definition(id).name());
+        graph
+            .ids_in_declaration_order()
+            .iter()
+            .flat_map(|&caller| {
+                graph
+                    .definition(caller)
+                    .outgoing()
+                    .iter()
+                    .map(move |&callee| (name(caller).to_string(), name(callee).to_string()))
+            })
+            .collect()
+    }
+
+    /// Definitions grouped into strongly connected components, dependencies
+    /// before dependents.
+    ///
+    /// A component with more than one name is mutual recursion; a single name
+    /// is recursive only if it also appears as its own callee in
+    /// [`dependency_edges`](Self::dependency_edges). Empty when analysis
+    /// stopped early.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<String>> {
+        let Some(analysis) = self.analysis() else {
+            return Vec::new();
+        };
+        let graph = &analysis.definitions;
+        graph
+            .sccs()
+            .iter()
+            .map(|scc| {
+                scc.iter()
+                    .map(|&id| {
+                        analysis
+                            .interner
+                            .resolve(graph.definition(id).name())
+                            .to_string()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
     #[allow(clippy::result_large_err)]
     fn into_analyzed(self) -> Result<AnalyzedQuery, Query> {
         if self.parsed.diag.has_errors() {
@@ -288,18 +413,9 @@ impl Query {
             Err(query) => return BindOutcome::Invalid(Box::new(query)),
         };
 
-        let mut output = GrammarBindingBuilder::new();
-        output.identity(grammar.identity().cloned());
-        bind::GrammarBindInput {
-            interner: &mut analyzed.analysis.interner,
-            grammar,
-            source_map: &analyzed.parsed.source_map,
-            definitions: &analyzed.analysis.definitions,
-            pattern_facts: &analyzed.analysis.pattern_facts,
-            strict_lints: analyzed.parsed.strict_lints,
-            satisfiability_limits: analyzed.parsed.limits.satisfiability(),
-        }
-        .bind(&mut output, &mut analyzed.parsed.diag);
+        let mut diag = std::mem::take(&mut analyzed.parsed.diag);
+        let binding = analyzed.bind_grammar(grammar, &mut diag);
+        analyzed.parsed.diag = diag;
 
         if analyzed.parsed.diag.has_errors() {
             return BindOutcome::Invalid(Box::new(analyzed.into_query()));
@@ -307,8 +423,44 @@ impl Query {
 
         BindOutcome::Bound(Box::new(BoundQuery {
             analyzed,
-            grammar: output.finish(),
+            grammar: binding,
+        }))
+    }
+
+    /// Bind this query to one grammar and lower it, leaving the query intact
+    /// for other grammars.
+    ///
+    /// Analysis runs once, in [`QueryBuilder::analyze`]; linking only checks
+    /// node kinds and fields against `grammar`. A failed link returns that
+    /// grammar's diagnostics alone, without the analysis diagnostics already in
+    /// [`diagnostics`](Self::diagnostics). A query that is not
+    /// [valid](Self::is_valid) never links and returns those analysis
+    /// diagnostics instead.
+    pub fn link(&self, grammar: &Grammar) -> Result<CompiledQuery, Diagnostics> {
+        if !self.is_valid() {
+            return Err(self.diagnostics().clone());
+        }
+        let analysis = self
+            .analysis
+            .clone()
+            .expect("valid query carries analysis artifacts");
+        let mut analyzed = AnalyzedQuery {
+            parsed: self.parsed.clone(),
+            analysis,
+        };
+
+        let mut link_diag = Diagnostics::new();
+        let binding = analyzed.bind_grammar(grammar, &mut link_diag);
+        if link_diag.has_errors() {
+            return Err(link_diag);
+        }
+        analyzed.parsed.diag.extend(link_diag);
+
+        Ok(BindOutcome::Bound(Box::new(BoundQuery {
+            analyzed,
+            grammar: binding,
         }))
+        .compile())
     }
 }
 
@@ -317,6 +469,24 @@ impl AnalyzedQuery {
         Query::analyzed(self.parsed, self.analysis)
     }
 
+    fn bind_grammar(&mut self, grammar: &Grammar, diag: &mut Diagnostics) -> GrammarBinding {
+        let started = self.parsed.timer.start();
+        let mut output = GrammarBindingBuilder::new();
+        output.identity(grammar.identity().cloned());
+        bind::GrammarBindInput {
+            interner: &mut self.analysis.interner,
+            grammar,
+            source_map: &self.parsed.source_map,
+            definitions: &self.analysis.definitions,
+            pattern_facts: &self.analysis.pattern_facts,
+            strict_lints: self.parsed.strict_lints,
+            satisfiability_limits: self.parsed.limits.satisfiability(),
+        }
+        .bind(&mut output, diag);
+        self.parsed.timer.record("bind", started);
+        output.finish()
+    }
+
     pub(crate) fn interner(&self) -> &Interner {
         &self.analysis.interner
     }
@@ -369,6 +539,7 @@ pub struct CompiledQuery {
     result: Option<ResultModel>,
     semantic_nfa: Option<SemanticNfa>,
     diagnostics: Diagnostics,
+    timer: PassTimer,
 }
 
 impl CompiledQuery {
@@ -376,18 +547,33 @@ impl CompiledQuery {
         self.result.is_some() && self.semantic_nfa.is_some() && !self.diagnostics.has_errors()
     }
 
+    /// Per-pass durations through lowering, when [`QueryBuilder::with_timing`]
+    /// was enabled. Each emission reports these plus its own `emit` entry
+    /// through [`Emission::timings`].
+    pub fn timings(&self) -> Option<&PassTimings> {
+        self.timer.timings()
+    }
+
     pub fn emit<T: EmitTarget>(
         &self,
         target: T,
     ) -> crate::compiler::QueryResult<Emission<T::Output>> {
-        target.emit(self)
+        let mut timer = self.timer.clone();
+        let started = timer.start();
+        let emission = target.emit(self)?;
+        timer.record("emit", started);
+        Ok(emission.with_timings(timer.into_timings()))
     }
 
     pub fn emit_types<T: crate::compiler::emit::CodegenTarget>(
         &self,
         target: T,
     ) -> crate::compiler::QueryResult<Emission<T::TypesOutput>> {
-        target.emit_types(self)
+        let mut timer = self.timer.clone();
+        let started = timer.start();
+        let emission = target.emit_types(self)?;
+        timer.record("emit", started);
+        Ok(emission.with_timings(timer.into_timings()))
     }
 
     pub(crate) fn emit_bytecode(
@@ -527,6 +713,39 @@ impl CompiledQuery {
         ))
     }
 
+    pub(crate) fn emit_python_types(
+        &self,
+        config: &PythonCodegenConfig,
+    ) -> crate::compiler::QueryResult<Emission<PythonTypesOutput>> {
+        if !self.is_valid() {
+            return Ok(Emission::invalid_query());
+        }
+        let schema = self.result_schema();
+        let source =
+            crate::compiler::emit::targets::python::emit_schema(&schema, config.emitter_config());
+        Ok(Emission::success(
+            PythonTypesOutput::new(source),
+            Diagnostics::new(),
+        ))
+    }
+
+    pub(crate) fn emit_json_schema(
+        &self,
+        config: &JsonSchemaConfig,
+    ) -> crate::compiler::QueryResult<Emission<JsonSchemaOutput>> {
+        if !self.is_valid() {
+            return Ok(Emission::invalid_query());
+        }
+        let root = config.root(self)?;
+        let schema = self.result_schema();
+        let document =
+            crate::compiler::emit::targets::json_schema::emit_schema(&schema, root.as_deref());
+        Ok(Emission::success(
+            JsonSchemaOutput::new(document),
+            Diagnostics::new(),
+        ))
+    }
+
     pub fn source_map(&self) -> &SourceMap {
         self.bound.source_map()
     }
@@ -581,6 +800,20 @@ impl CompiledQuery {
             colors,
         ))
     }
+
+    /// Render the same NFA as [`Self::dump_nfa`] as a Graphviz DOT digraph:
+    /// one node per instruction, clustered by definition. `None` when the
+    /// query didn't compile.
+    pub fn dump_nfa_dot(&self) -> Option<String> {
+        let semantic = self.semantic_nfa.as_ref()?;
+        let bound = self.bound.bound()?;
+        let schema = self.result_schema();
+        Some(crate::compiler::lower::dump::dump_nfa_dot(
+            semantic,
+            bound.analysis_input(),
+            schema.layout(),
+        ))
+    }
 }
 
 impl BindOutcome {
@@ -623,15 +856,17 @@ impl BindOutcome {
         crate::compiler::emit::targets::bytecode::emit(bound.analysis_input(), &schema, &lowered)
     }
 
-    pub(crate) fn compile(self) -> crate::compiler::QueryResult<CompiledQuery> {
+    pub(crate) fn compile(self) -> CompiledQuery {
         let mut diagnostics = self.diagnostics().clone();
+        let mut timer = self.timer().clone();
         let Some(bound) = self.bound() else {
-            return Ok(CompiledQuery {
+            return CompiledQuery {
                 bound: self,
                 result: None,
                 semantic_nfa: None,
                 diagnostics,
-            });
+                timer,
+            };
         };
         assert!(
             !diagnostics.has_errors(),
@@ -639,16 +874,19 @@ impl BindOutcome {
              binding must not expose artifacts"
         );
 
+        let started = timer.start();
         let result = match ResultModel::from_artifacts(bound.analysis_input()) {
             Ok(result) => result,
             Err(error) => {
                 self.report_shared_limit_error(&mut diagnostics, error.to_string());
-                return Ok(CompiledQuery {
+                timer.record("lower", started);
+                return CompiledQuery {
                     bound: self,
                     result: None,
                     semantic_nfa: None,
                     diagnostics,
-                });
+                    timer,
+                };
             }
         };
         let input = LowerInput {
@@ -657,12 +895,21 @@ impl BindOutcome {
             inspection: false,
         };
         let semantic_nfa = lower_semantic(&input);
-        Ok(CompiledQuery {
+        timer.record("lower", started);
+        CompiledQuery {
             bound: self,
             result: Some(result),
             semantic_nfa: Some(semantic_nfa),
             diagnostics,
-        })
+            timer,
+        }
+    }
+
+    fn timer(&self) -> &PassTimer {
+        match self {
+            BindOutcome::Bound(query) => &query.analyzed.parsed.timer,
+            BindOutcome::Invalid(query) => &query.parsed.timer,
+        }
     }
 
     fn bound(&self) -> Option<&BoundQuery> {
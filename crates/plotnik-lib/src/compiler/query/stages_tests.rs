@@ -0,0 +1,147 @@
+use indoc::indoc;
+
+use super::{Query, QueryBuilder};
+use crate::compiler::diagnostics::DiagnosticKind;
+use crate::compiler::test_utils::synthetic_grammar as grammar;
+
+const QUERY: &str = indoc! {"
+    Expr = [(identifier) @name (call_expression arguments: (Args))]
+    Args = (arguments (Expr)*)
+    Main = (program (expression_statement (Expr) @expr))
+"};
+
+fn analyze() -> Query {
+    let query = Query::try_from(QUERY).expect("query analyzes");
+    assert!(
+        query.is_valid(),
+        "{}",
+        query.diagnostics().render(query.source_map())
+    );
+    query
+}
+
+#[test]
+fn edges_run_from_caller_to_callee() {
+    let query = analyze();
+
+    let edges = query.dependency_edges();
+
+    let edges: Vec<_> = edges
+        .iter()
+        .map(|(caller, callee)| (caller.as_str(), callee.as_str()))
+        .collect();
+    assert_eq!(
+        edges,
+        [("Expr", "Args"), ("Args", "Expr"), ("Main", "Expr")]
+    );
+}
+
+#[test]
+fn mutual_recursion_shares_a_component() {
+    let query = analyze();
+
+    let mut components = query.strongly_connected_components();
+
+    for component in &mut components {
+        component.sort();
+    }
+    assert_eq!(components, [vec!["Args", "Expr"], vec!["Main"]]);
+}
+
+#[test]
+fn link_keeps_the_query_for_another_grammar() {
+    let query = Query::try_from("Q = (call_expression function: (identifier) @callee)").unwrap();
+
+    let first = query.link(grammar()).expect("query links");
+    let second = query.link(grammar()).expect("query links again");
+
+    assert!(first.is_valid());
+    assert!(second.is_valid());
+}
+
+#[test]
+fn link_errors_hold_only_grammar_diagnostics() {
+    let query = Query::try_from("Q = (call_expression increment: (_) @step)").unwrap();
+
+    let Err(diagnostics) = query.link(grammar()) else {
+        panic!("`increment` is not a call_expression field");
+    };
+
+    assert_eq!(
+        diagnostics.kinds().collect::<Vec<_>>(),
+        [DiagnosticKind::UnknownGrammarField]
+    );
+    assert!(query.is_valid());
+    assert!(query.diagnostics().is_empty());
+}
+
+const TYPING: &str = "Done = (identifier) @name\nTyping = (program (identifier\n";
+
+#[test]
+fn invalid_query_does_not_link() {
+    let query = analyze_on_error(TYPING);
+
+    let result = query.link(grammar());
+
+    let Err(diagnostics) = result else {
+        panic!("an invalid query must not link");
+    };
+    assert!(diagnostics.has_errors());
+    assert_eq!(
+        diagnostics.kinds().collect::<Vec<_>>(),
+        query.diagnostics().kinds().collect::<Vec<_>>()
+    );
+}
+
+fn analyze_on_error(text: &str) -> Query {
+    QueryBuilder::from_inline(text)
+        .with_analyze_on_error(true)
+        .analyze()
+        .expect("query analyzes")
+}
+
+#[test]
+fn provisional_analysis_keeps_completed_definition_types() {
+    let query = analyze_on_error(TYPING);
+
+    assert!(query.is_provisional());
+    assert!(!query.is_valid());
+    assert!(query.diagnostics().has_errors());
+
+    let captures = query.captures();
+    assert_eq!(captures.len(), 1);
+    assert_eq!(captures[0].name, "name");
+    assert_eq!(captures[0].type_description, "Node");
+    assert_eq!(captures[0].definition, "Done");
+}
+
+#[test]
+fn provisional_reference_to_an_erroring_definition_is_not_undefined() {
+    let query = analyze_on_error("Main = (program (Typing) @t)\nTyping = (program (identifier\n");
+
+    assert!(query.is_provisional());
+    assert!(
+        query
+            .diagnostics()
+            .kinds()
+            .all(|kind| kind != DiagnosticKind::UndefinedReference),
+        "{}",
+        query.diagnostics().render(query.source_map())
+    );
+}
+
+#[test]
+fn errors_stop_analysis_by_default() {
+    let query = Query::try_from(TYPING).expect("query parses");
+
+    assert!(!query.is_provisional());
+    assert!(query.captures().is_empty());
+}
+
+#[test]
+fn clean_source_is_not_provisional() {
+    let query = analyze_on_error("Done = (identifier) @name\n");
+
+    assert!(!query.is_provisional());
+    assert!(query.is_valid());
+}
@@ -0,0 +1,68 @@
+//! Opt-in wall-clock timing of compiler passes.
+
+use std::time::{Duration, Instant};
+
+/// Elapsed time of each compiler pass, in the order the passes ran.
+///
+/// Pass names are `parse`, `validate`, `names`, `dependencies`, and `types`
+/// for analysis, then `bind` and `lower` for compilation. An [`Emission`]
+/// adds a final `emit` entry for its target. A pipeline that stopped early
+/// lists only the passes that ran.
+///
+/// [`Emission`]: crate::Emission
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PassTimings {
+    passes: Vec<(&'static str, Duration)>,
+}
+
+impl PassTimings {
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, Duration)> + '_ {
+        self.passes.iter().copied()
+    }
+
+    pub fn get(&self, pass: &str) -> Option<Duration> {
+        self.iter()
+            .find(|&(name, _)| name == pass)
+            .map(|(_, elapsed)| elapsed)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.iter().map(|(_, elapsed)| elapsed).sum()
+    }
+}
+
+/// Collects [`PassTimings`] when enabled. A disabled timer never reads the
+/// clock, so targets without one (`wasm32-unknown-unknown`) pay nothing.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PassTimer {
+    timings: Option<PassTimings>,
+}
+
+impl PassTimer {
+    pub(crate) fn new(enabled: bool) -> Self {
+        Self {
+            timings: enabled.then(PassTimings::default),
+        }
+    }
+
+    /// The current instant when timing is on; hand it back to [`Self::record`].
+    pub(crate) fn start(&self) -> Option<Instant> {
+        self.timings.as_ref().map(|_| Instant::now())
+    }
+
+    pub(crate) fn record(&mut self, pass: &'static str, started: Option<Instant>) {
+        if let Some(timings) = &mut self.timings
+            && let Some(started) = started
+        {
+            timings.passes.push((pass, started.elapsed()));
+        }
+    }
+
+    pub(crate) fn timings(&self) -> Option<&PassTimings> {
+        self.timings.as_ref()
+    }
+
+    pub(crate) fn into_timings(self) -> Option<PassTimings> {
+        self.timings
+    }
+}
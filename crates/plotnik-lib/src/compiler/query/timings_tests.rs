@@ -0,0 +1,85 @@
+use indoc::indoc;
+
+use crate::compiler::test_utils::synthetic_grammar as grammar;
+use crate::compiler::{BytecodeConfig, QueryBuilder};
+
+const QUERY: &str = indoc! {"
+    Param = (identifier) @name
+    Params = (formal_parameters (Param)* @params)
+    Function = (function_declaration
+      name: (identifier) @name
+      parameters: (Params) @params
+      body: (statement_block (expression_statement)* @statements))
+    Main = (program (Function)* @functions)
+"};
+
+const COMPILE_PASSES: [&str; 7] = [
+    "parse",
+    "validate",
+    "names",
+    "dependencies",
+    "types",
+    "bind",
+    "lower",
+];
+
+#[test]
+fn every_pass_is_timed_in_pipeline_order() {
+    let compiled = QueryBuilder::from_inline(QUERY)
+        .with_timing(true)
+        .compile(grammar())
+        .expect("query compiles");
+
+    let timings = compiled.timings().expect("timing was enabled");
+
+    assert!(compiled.is_valid());
+    let passes: Vec<_> = timings.iter().map(|(name, _)| name).collect();
+    assert_eq!(passes, COMPILE_PASSES);
+    assert!(!timings.total().is_zero());
+}
+
+#[test]
+fn emission_appends_its_emit_timing() {
+    let compiled = QueryBuilder::from_inline(QUERY)
+        .with_timing(true)
+        .compile(grammar())
+        .expect("query compiles");
+
+    let emission = compiled
+        .emit(BytecodeConfig::new())
+        .expect("bytecode emission answers");
+
+    let timings = emission.timings().expect("timing was enabled");
+    let passes: Vec<_> = timings.iter().map(|(name, _)| name).collect();
+    assert_eq!(passes[..COMPILE_PASSES.len()], COMPILE_PASSES);
+    assert_eq!(passes[COMPILE_PASSES.len()..], ["emit"]);
+    assert_eq!(compiled.timings().map(|t| t.get("emit")), Some(None));
+}
+
+#[test]
+fn analysis_timings_stop_before_binding() {
+    let query = QueryBuilder::from_inline(QUERY)
+        .with_timing(true)
+        .analyze()
+        .expect("query analyzes");
+
+    let timings = query.timings().expect("timing was enabled");
+
+    assert!(timings.get("types").is_some());
+    assert_eq!(timings.get("bind"), None);
+}
+
+#[test]
+fn timing_is_off_by_default() {
+    let query = QueryBuilder::from_inline(QUERY)
+        .analyze()
+        .expect("query analyzes");
+    let emission = QueryBuilder::from_inline(QUERY)
+        .compile(grammar())
+        .expect("query compiles")
+        .emit(BytecodeConfig::new())
+        .expect("bytecode emission answers");
+
+    assert!(query.timings().is_none());
+    assert!(emission.timings().is_none());
+}
@@ -0,0 +1,39 @@
+//! Compact summary of what each entry point of an analyzed query produces.
+
+use std::fmt::Write as _;
+
+use crate::compiler::analyze::types::type_shape::DefinitionOutput;
+use crate::compiler::query::Query;
+
+impl Query {
+    /// One `Name: type` line per entry point, in declaration order.
+    ///
+    /// Types use the diagnostic notation without elision. Empty when analysis
+    /// stopped before types were inferred.
+    pub fn type_report(&self) -> String {
+        let Some(analysis) = self.analysis() else {
+            return String::new();
+        };
+        let graph = &analysis.definitions;
+        let types = &analysis.type_analysis;
+
+        let mut report = String::new();
+        for &def_id in graph.ids_in_declaration_order() {
+            if !analysis.pattern_facts.is_entry_point_eligible(def_id) {
+                continue;
+            }
+            let Some(output) = types.def_output(def_id) else {
+                continue;
+            };
+            let description = match output {
+                DefinitionOutput::MatchOnly => "match only".to_string(),
+                DefinitionOutput::Value(type_id) => {
+                    types.describe_type_in_full(&analysis.interner, type_id)
+                }
+            };
+            let name = analysis.interner.resolve(graph.definition(def_id).name());
+            let _ = writeln!(report, "{name}: {description}");
+        }
+        report
+    }
+}
@@ -0,0 +1,19 @@
+use crate::compiler::query::Query;
+
+#[test]
+fn reports_struct_array_and_variant_results() {
+    let query = Query::try_from(
+        r#"
+        Binary = (binary_expression left: (_) @lhs right: (_) @rhs)
+        Numbers = (array (number)* @items)
+        Literal = [Num: (number) @n  Str: (string) @s]
+        "#,
+    )
+    .expect("query parses");
+
+    insta::assert_snapshot!(query.type_report(), @r"
+    Binary: { lhs: Node, rhs: Node }
+    Numbers: { items: Node[] }
+    Literal: variant Num({ n: Node }) | Str({ s: Node })
+    ");
+}
@@ -9,6 +9,8 @@ mod node_shapes;
 mod prepared;
 mod productions;
 pub mod raw;
+#[cfg(test)]
+mod raw_tests;
 mod render;
 mod rules;
 mod structure;
@@ -51,6 +51,33 @@ impl RawGrammar {
     pub fn to_json(&self) -> Result<String, GrammarError> {
         serde_json::to_string(self).map_err(GrammarError::Json)
     }
+
+    /// The rule defined under `name`, if any.
+    pub fn rule(&self, name: &str) -> Option<&RawRule> {
+        self.rules.get(name)
+    }
+
+    /// Rule names in definition order.
+    pub fn rule_names(&self) -> impl Iterator<Item = &str> {
+        self.rules.keys().map(String::as_str)
+    }
+
+    /// Precedence orderings that mention `name`, as a named precedence
+    /// (`STRING`) or a rule (`SYMBOL`), in declaration order.
+    pub fn precedence_orderings_of<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a [RawPrecedenceEntry]> {
+        self.precedences
+            .iter()
+            .filter(move |ordering| ordering.iter().any(|entry| entry.name() == name))
+            .map(Vec::as_slice)
+    }
+
+    /// Whether `name` is declared as a supertype rule.
+    pub fn is_supertype(&self, name: &str) -> bool {
+        self.supertypes.iter().any(|supertype| supertype == name)
+    }
 }
 
 /// Raw rule matching tree-sitter's `grammar.json` format.
@@ -135,3 +162,13 @@ pub enum RawPrecedenceEntry {
     STRING { value: String },
     SYMBOL { name: String },
 }
+
+impl RawPrecedenceEntry {
+    /// The precedence name or rule name this entry orders.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::STRING { value } => value,
+            Self::SYMBOL { name } => name,
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use indoc::indoc;
+
+use super::raw::{RawGrammar, RawPrecedenceEntry, RawRule};
+
+fn grammar() -> RawGrammar {
+    RawGrammar::from_json(indoc! {r#"
+        {
+          "name": "calc",
+          "rules": {
+            "program": { "type": "REPEAT", "content": { "type": "SYMBOL", "name": "_expression" } },
+            "_expression": {
+              "type": "CHOICE",
+              "members": [
+                { "type": "SYMBOL", "name": "binary" },
+                { "type": "SYMBOL", "name": "number" }
+              ]
+            },
+            "binary": {
+              "type": "PREC_LEFT",
+              "value": "sum",
+              "content": {
+                "type": "SEQ",
+                "members": [
+                  { "type": "SYMBOL", "name": "_expression" },
+                  { "type": "STRING", "value": "+" },
+                  { "type": "SYMBOL", "name": "_expression" }
+                ]
+              }
+            },
+            "number": { "type": "PATTERN", "value": "\\d+" }
+          },
+          "precedences": [
+            [{ "type": "STRING", "value": "product" }, { "type": "STRING", "value": "sum" }],
+            [{ "type": "SYMBOL", "name": "binary" }, { "type": "SYMBOL", "name": "number" }]
+          ],
+          "supertypes": ["_expression"]
+        }
+    "#})
+    .expect("hand-written grammar parses")
+}
+
+#[test]
+fn rules_are_listed_in_definition_order() {
+    let grammar = grammar();
+
+    assert_eq!(
+        grammar.rule_names().collect::<Vec<_>>(),
+        ["program", "_expression", "binary", "number"]
+    );
+    assert!(matches!(
+        grammar.rule("number"),
+        Some(RawRule::PATTERN { value, .. }) if value == "\\d+"
+    ));
+    assert_eq!(grammar.rule("missing"), None);
+}
+
+#[test]
+fn precedence_orderings_match_names_and_symbols() {
+    let grammar = grammar();
+
+    let sum: Vec<_> = grammar.precedence_orderings_of("sum").collect();
+    assert_eq!(sum.len(), 1);
+    assert_eq!(
+        sum[0]
+            .iter()
+            .map(RawPrecedenceEntry::name)
+            .collect::<Vec<_>>(),
+        ["product", "sum"]
+    );
+
+    let binary: Vec<_> = grammar.precedence_orderings_of("binary").collect();
+    assert_eq!(binary.len(), 1);
+    assert!(matches!(binary[0][0], RawPrecedenceEntry::SYMBOL { .. }));
+
+    assert_eq!(grammar.precedence_orderings_of("program").count(), 0);
+}
+
+#[test]
+fn supertypes_are_reported_by_rule_name() {
+    let grammar = grammar();
+
+    assert!(grammar.is_supertype("_expression"));
+    assert!(!grammar.is_supertype("binary"));
+}
+
+#[test]
+fn lookups_leave_serialization_intact() {
+    let grammar = grammar();
+    let json = grammar.to_json().expect("raw grammar serializes");
+
+    assert_eq!(
+        RawGrammar::from_json(&json).expect("serialized grammar parses"),
+        grammar
+    );
+}
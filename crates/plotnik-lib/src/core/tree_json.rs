@@ -9,7 +9,16 @@ use serde_json::{Map, Value};
 /// cannot overflow the native stack while building diagnostics or playground
 /// payloads.
 pub fn tree_to_json(tree: &tree_sitter::Tree, _source: &str, include_anonymous: bool) -> Value {
-    let mut stack = vec![Frame::new(tree.root_node(), None, include_anonymous)];
+    subtree_to_json(tree.root_node(), include_anonymous)
+}
+
+/// Serialize the subtree rooted at `node` in the [`tree_to_json`] shape.
+///
+/// A captured [`NodeValue`](crate::NodeValue) carries only its span; recover
+/// the tree node with `named_descendant_for_byte_range` to dump what it
+/// matched. The root's own field name is not reported.
+pub fn subtree_to_json(node: tree_sitter::Node<'_>, include_anonymous: bool) -> Value {
+    let mut stack = vec![Frame::new(node, None, include_anonymous)];
 
     loop {
         let next_child = {
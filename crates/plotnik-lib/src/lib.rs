@@ -30,13 +30,14 @@ pub use crate::core::utils as text_utils;
 pub use crate::core::{Cardinality, NodeFieldId, NodeKind, NodeKindId};
 #[cfg(feature = "vm")]
 pub use crate::core::{
-    DumpChunk, DumpChunkKind, DumpNode, TreeDump, dump_tree, dump_tree_text, tree_to_json,
+    DumpChunk, DumpChunkKind, DumpNode, TreeDump, dump_tree, dump_tree_text, subtree_to_json,
+    tree_to_json,
 };
 
 pub mod diagnostics {
     pub use crate::compiler::diagnostics::report::{
-        DiagnosticBuilder, DiagnosticKind, Diagnostics, JsonDiagnostic, JsonFix, JsonPosition,
-        JsonRelated, JsonSpan, Severity,
+        DiagnosticBuilder, DiagnosticKind, Diagnostics, FixConflict, JsonDiagnostic, JsonFix,
+        JsonPosition, JsonRelated, JsonSpan, Severity,
     };
     pub use crate::compiler::diagnostics::{
         Error, QueryResult, Source, SourceId, SourceKind, SourceMap, SourcePath, Span,
@@ -52,19 +53,22 @@ pub use crate::core::grammar::GrammarIdentity;
 pub use crate::compiler::{
     BytecodeConfig, BytecodeInspection, CodegenProvenance, CodegenTarget, DiagnosticBuilder,
     DiagnosticKind, Diagnostics, Emission, EmitConfigError, EmitTarget, Error, FormatError,
-    FormatResult, QueryResult, RustCodegenConfig, RustModuleOutput, RustTypesOutput, Severity,
-    Span, TypeScriptCodegenConfig, TypeScriptNodeRepresentation, TypeScriptTypesOutput,
+    FormatResult, JsonSchemaConfig, JsonSchemaOutput, PythonCodegenConfig, PythonTypesOutput,
+    QueryResult, RustCodegenConfig, RustModuleOutput, RustTypesOutput, Severity, Span,
+    TypeScriptCodegenConfig, TypeScriptNodeRepresentation, TypeScriptTypesOutput,
 };
 pub use crate::compiler::{
-    CompiledQuery, Query, QueryBuilder, QueryToken, Source, SourceId, SourceKind, SourceMap,
-    SourcePath, format_query, tokenize,
+    CaptureInfo, CompiledQuery, Completion, HoverInfo, PassTimings, Query, QueryBuilder,
+    QueryToken, SemanticToken, Source, SourceId, SourceKind, SourceMap, SourcePath, completions_at,
+    format_query, tokenize,
 };
 
 #[cfg(feature = "vm")]
 pub use crate::vm::{
-    ExecutionTrace, JournalEvent, Limit, MatchJournal, NodeValue, NoopTracer, OutputEvents,
-    PrintTracer, PrintTracerBuilder, ProvenanceBinding, ResolvedRuntimeLimits,
-    ResultProvenanceEntry, RunStats, RuntimeError, RuntimeLimitSpec, TraceEvent, TraceNode,
-    TraceRecord, TraceRecorder, Tracer, VM, VMBuilder, Value, ValueMaterializer, Verbosity,
-    debug_verify_type, extract_result_provenance, materialize_verified,
+    ExecutionTrace, JournalEvent, JsonMaterializer, LanguageSymbol, Limit, MatchJournal, NodeValue,
+    NoopTracer, OutputEvents, PrintTracer, PrintTracerBuilder, ProvenanceBinding,
+    ResolvedRuntimeLimits, ResultProvenanceEntry, RunStats, RuntimeError, RuntimeLimitSpec,
+    TraceEvent, TraceNode, TraceRecord, TraceRecorder, Tracer, VM, VMBuilder, Value,
+    ValueMaterializer, Verbosity, debug_verify_type, extract_result_provenance,
+    materialize_verified,
 };
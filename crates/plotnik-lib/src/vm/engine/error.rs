@@ -16,11 +16,48 @@ pub enum RuntimeError {
     #[error("exceeded the memory limit of {limit} bytes (used {used} bytes)")]
     MemoryLimitExceeded { used: u64, limit: u64 },
 
+    /// The run was still going when the deadline set with
+    /// [`VMBuilder::deadline`](super::VMBuilder::deadline) passed.
+    #[error("exceeded the execution deadline")]
+    Timeout,
+
     #[error(transparent)]
     CallFrame(#[from] CallFrameError),
 
     #[error("no match found")]
     NoMatch,
+
+    /// The tree's language names a module symbol differently (or not at all),
+    /// so the module's baked ids would silently mis-match. Only the first
+    /// disagreement is reported; `found` is the tree's name for that id.
+    /// Raised by [`VM::execute_checked`](super::VM::execute_checked).
+    #[error(
+        "tree language does not match the module: {symbol} {id} is {expected:?} in the \
+         module, but the tree's language says {found:?}"
+    )]
+    LanguageMismatch {
+        symbol: LanguageSymbol,
+        id: u16,
+        expected: String,
+        found: Option<String>,
+    },
+}
+
+/// Which of the module's symbol tables a [`RuntimeError::LanguageMismatch`]
+/// was found in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LanguageSymbol {
+    NodeKind,
+    Field,
+}
+
+impl std::fmt::Display for LanguageSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::NodeKind => "node kind",
+            Self::Field => "field",
+        })
+    }
 }
 
 /// Non-error outcomes that unwind a dispatch back to the main execution loop.
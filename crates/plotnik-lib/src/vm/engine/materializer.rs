@@ -1,4 +1,11 @@
 //! Materializes committed match journals into result values.
+//!
+//! One event walk serves every output representation; a [`ValueShape`] only
+//! supplies the constructors for nodes, scalars, lists, records, and variants.
+
+use std::collections::BTreeMap;
+
+use serde_json::Map;
 
 use crate::bytecode::{EntryPoint, Module};
 use crate::core::Colors;
@@ -52,13 +59,186 @@ pub fn materialize_verified<'s>(
     value
 }
 
+/// Constructors for one output representation of a result value.
+trait ValueShape<'s> {
+    type Output;
+
+    fn node(node: NodeValue<'s>) -> Self::Output;
+    fn absent() -> Self::Output;
+    fn text(text: &'s str) -> Self::Output;
+    fn bool(value: bool) -> Self::Output;
+    fn list(items: Vec<Self::Output>) -> Self::Output;
+    fn record(fields: Vec<(&'s str, Self::Output)>) -> Self::Output;
+    fn variant(case: &'s str, payload: Option<Self::Output>) -> Self::Output;
+}
+
+/// Builds the engine's own [`Value`].
+struct NativeShape;
+
+impl<'s> ValueShape<'s> for NativeShape {
+    type Output = Value<'s>;
+
+    fn node(node: NodeValue<'s>) -> Value<'s> {
+        Value::Node(node)
+    }
+
+    fn absent() -> Value<'s> {
+        Value::Absent
+    }
+
+    fn text(text: &'s str) -> Value<'s> {
+        Value::Text(text)
+    }
+
+    fn bool(value: bool) -> Value<'s> {
+        Value::Bool(value)
+    }
+
+    fn list(items: Vec<Value<'s>>) -> Value<'s> {
+        Value::List(items)
+    }
+
+    fn record(fields: Vec<(&'s str, Value<'s>)>) -> Value<'s> {
+        Value::Record(fields)
+    }
+
+    fn variant(case: &'s str, payload: Option<Value<'s>>) -> Value<'s> {
+        Value::Variant {
+            case,
+            payload: payload.map(Box::new),
+        }
+    }
+}
+
+/// Builds the JSON that serializing a [`Value`] produces.
+struct JsonShape;
+
+impl<'s> ValueShape<'s> for JsonShape {
+    type Output = serde_json::Value;
+
+    fn node(node: NodeValue<'s>) -> serde_json::Value {
+        serde_json::json!({
+            "kind": node.kind,
+            "text": node.text,
+            "span": [node.span.0, node.span.1],
+        })
+    }
+
+    fn absent() -> serde_json::Value {
+        serde_json::Value::Null
+    }
+
+    fn text(text: &'s str) -> serde_json::Value {
+        serde_json::Value::from(text)
+    }
+
+    fn bool(value: bool) -> serde_json::Value {
+        serde_json::Value::Bool(value)
+    }
+
+    fn list(items: Vec<serde_json::Value>) -> serde_json::Value {
+        serde_json::Value::Array(items)
+    }
+
+    fn record(fields: Vec<(&'s str, serde_json::Value)>) -> serde_json::Value {
+        serde_json::Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        )
+    }
+
+    fn variant(case: &'s str, payload: Option<serde_json::Value>) -> serde_json::Value {
+        let mut map = Map::new();
+        map.insert("$tag".to_string(), serde_json::Value::from(case));
+        if let Some(payload) = payload {
+            map.insert("$data".to_string(), payload);
+        }
+        serde_json::Value::Object(map)
+    }
+}
+
+/// Per-field hit counts of the subtree a value would cover.
+#[derive(Default)]
+struct CaptureHits<'s> {
+    /// Whether the value itself exists; an absent field is not a hit.
+    present: bool,
+    hits: BTreeMap<&'s str, u64>,
+}
+
+impl<'s> CaptureHits<'s> {
+    fn present() -> Self {
+        Self {
+            present: true,
+            hits: BTreeMap::new(),
+        }
+    }
+
+    fn absorb(&mut self, child: CaptureHits<'s>) {
+        for (name, count) in child.hits {
+            *self.hits.entry(name).or_default() += count;
+        }
+    }
+}
+
+/// Counts field hits instead of building values.
+struct HitCountShape;
+
+impl<'s> ValueShape<'s> for HitCountShape {
+    type Output = CaptureHits<'s>;
+
+    fn node(_: NodeValue<'s>) -> CaptureHits<'s> {
+        CaptureHits::present()
+    }
+
+    fn absent() -> CaptureHits<'s> {
+        CaptureHits::default()
+    }
+
+    fn text(_: &'s str) -> CaptureHits<'s> {
+        CaptureHits::present()
+    }
+
+    fn bool(_: bool) -> CaptureHits<'s> {
+        CaptureHits::present()
+    }
+
+    fn list(items: Vec<CaptureHits<'s>>) -> CaptureHits<'s> {
+        let mut out = CaptureHits::present();
+        for item in items {
+            out.absorb(item);
+        }
+        out
+    }
+
+    fn record(fields: Vec<(&'s str, CaptureHits<'s>)>) -> CaptureHits<'s> {
+        let mut out = CaptureHits::present();
+        for (name, field) in fields {
+            if field.present {
+                *out.hits.entry(name).or_default() += 1;
+            }
+            out.absorb(field);
+        }
+        out
+    }
+
+    fn variant(_: &'s str, payload: Option<CaptureHits<'s>>) -> CaptureHits<'s> {
+        let mut out = CaptureHits::present();
+        if let Some(payload) = payload {
+            out.absorb(payload);
+        }
+        out
+    }
+}
+
 /// Value accumulator for stack-based materialization.
-enum ValueAccumulator<'s> {
-    List(Vec<Value<'s>>),
-    Record(Vec<(&'s str, Value<'s>)>),
+enum ValueAccumulator<'s, T> {
+    List(Vec<T>),
+    Record(Vec<(&'s str, T)>),
     Variant {
         case: &'s str,
-        fields: Vec<(&'s str, Value<'s>)>,
+        fields: Vec<(&'s str, T)>,
     },
     /// Marker into the scalar-only range stack. Keeping the marker here
     /// preserves heterogeneous frame nesting checks without making ScalarMark
@@ -66,7 +246,7 @@ enum ValueAccumulator<'s> {
     Scalar(usize),
 }
 
-impl ValueAccumulator<'_> {
+impl<T> ValueAccumulator<'_, T> {
     fn kind(&self) -> &'static str {
         match self {
             ValueAccumulator::List(_) => "List",
@@ -79,28 +259,41 @@ impl ValueAccumulator<'_> {
 
 impl<'a> ValueMaterializer<'a> {
     pub fn materialize(&self, events: OutputEvents<'_, '_>) -> Value<'a> {
-        let mut stack: Vec<ValueAccumulator<'a>> = vec![];
+        self.walk::<NativeShape>(events)
+    }
+
+    /// How many times each field name received a present value, at any depth.
+    ///
+    /// Walks the same events as [`Self::materialize`] without building the
+    /// [`Value`]: each element of a captured list counts, and a field left
+    /// absent by an unmatched optional does not.
+    pub fn capture_hits(&self, events: OutputEvents<'_, '_>) -> BTreeMap<&'a str, u64> {
+        self.walk::<HitCountShape>(events).hits
+    }
+
+    fn walk<S: ValueShape<'a>>(&self, events: OutputEvents<'_, '_>) -> S::Output {
+        let mut stack: Vec<ValueAccumulator<'a, S::Output>> = vec![];
         let mut scalar_ranges: Vec<Option<std::ops::Range<usize>>> = vec![];
 
         // Pending result value attached by `RecordSet` or `ArrayPush`.
-        let mut pending: Option<Value<'a>> = None;
+        let mut pending: Option<S::Output> = None;
 
         for (event_idx, event) in events.iter().enumerate() {
             match event {
                 JournalEvent::Node(n) => {
-                    pending = Some(Value::Node(NodeValue::from_node(*n, self.source)));
+                    pending = Some(S::node(NodeValue::from_node(*n, self.source)));
                 }
                 JournalEvent::Absent => {
-                    pending = Some(Value::Absent);
+                    pending = Some(S::absent());
                 }
                 JournalEvent::NodeText(node) => {
-                    pending = Some(Value::Text(plotnik_runtime::node_text(self.source, node)));
+                    pending = Some(S::text(plotnik_runtime::node_text(self.source, node)));
                 }
                 JournalEvent::NodeBool(_) => {
-                    pending = Some(Value::Bool(true));
+                    pending = Some(S::bool(true));
                 }
                 JournalEvent::BoolValue(value) => {
-                    pending = Some(Value::Bool(*value));
+                    pending = Some(S::bool(*value));
                 }
                 JournalEvent::ScalarOpen => {
                     let scalar = scalar_ranges.len();
@@ -135,10 +328,8 @@ impl<'a> ValueMaterializer<'a> {
                         .pop()
                         .expect("Scalar marker owns a range frame");
                     pending = Some(match range {
-                        Some(range) => {
-                            Value::Text(plotnik_runtime::source_text(self.source, range))
-                        }
-                        None => Value::Absent,
+                        Some(range) => S::text(plotnik_runtime::source_text(self.source, range)),
+                        None => S::absent(),
                     });
                 }
                 JournalEvent::BoolClose(value) => {
@@ -157,7 +348,7 @@ impl<'a> ValueMaterializer<'a> {
                     scalar_ranges
                         .pop()
                         .expect("Scalar marker owns a range frame");
-                    pending = Some(Value::Bool(*value));
+                    pending = Some(S::bool(*value));
                 }
                 JournalEvent::SpanStart { .. } | JournalEvent::SpanEnd(_) => {}
                 JournalEvent::ListOpen => {
@@ -183,7 +374,7 @@ impl<'a> ValueMaterializer<'a> {
                             top.as_ref().map(|b| b.kind())
                         );
                     };
-                    pending = Some(Value::List(items));
+                    pending = Some(S::list(items));
                 }
                 JournalEvent::RecordOpen => {
                     stack.push(ValueAccumulator::Record(vec![]));
@@ -212,7 +403,7 @@ impl<'a> ValueMaterializer<'a> {
                             top.as_ref().map(|b| b.kind())
                         );
                     };
-                    pending = Some(Value::Record(fields));
+                    pending = Some(S::record(fields));
                 }
                 JournalEvent::VariantOpen(idx) => {
                     let case = self.resolve_member_name(*idx);
@@ -230,8 +421,8 @@ impl<'a> ValueMaterializer<'a> {
                         );
                     };
                     let payload = match (pending.take(), fields.is_empty()) {
-                        (Some(v), true) => Some(Box::new(v)),
-                        (None, false) => Some(Box::new(Value::Record(fields))),
+                        (Some(v), true) => Some(v),
+                        (None, false) => Some(S::record(fields)),
                         (None, true) => None,
                         (Some(_), false) => {
                             panic!(
@@ -239,7 +430,7 @@ impl<'a> ValueMaterializer<'a> {
                             )
                         }
                     };
-                    pending = Some(Value::Variant { case, payload });
+                    pending = Some(S::variant(case, payload));
                 }
             }
         }
@@ -252,6 +443,29 @@ impl<'a> ValueMaterializer<'a> {
             scalar_ranges.is_empty(),
             "unclosed scalar frames after materialization"
         );
-        pending.unwrap_or(Value::Absent)
+        pending.unwrap_or_else(S::absent)
+    }
+}
+
+/// Materializes committed match journals straight into [`serde_json::Value`],
+/// skipping the intermediate [`Value`].
+///
+/// The JSON equals serializing the [`Value`] that [`ValueMaterializer`] would
+/// build. Unlike [`Value`], a `serde_json::Value` drops recursively, so a result
+/// nested as deep as a captured-recursive match can exhaust the native stack
+/// when it is dropped; prefer [`ValueMaterializer`] for unbounded inputs.
+pub struct JsonMaterializer<'a> {
+    inner: ValueMaterializer<'a>,
+}
+
+impl<'a> JsonMaterializer<'a> {
+    pub fn new(source: &'a str, module: &'a Module) -> Self {
+        Self {
+            inner: ValueMaterializer::new(source, module),
+        }
+    }
+
+    pub fn materialize(&self, events: OutputEvents<'_, '_>) -> serde_json::Value {
+        self.inner.walk::<JsonShape>(events)
     }
 }
@@ -12,6 +12,9 @@ mod value;
 mod verify;
 mod vm;
 
+#[cfg(test)]
+mod value_tests;
+
 // Navigation, checkpoints, frames, the match journal, and limits live in
 // `plotnik-rt`, shared with the generated-code backend; re-exported so the
 // crate-facing paths stay `vm::...`.
@@ -19,9 +22,9 @@ pub use plotnik_runtime::{
     JournalEvent, Limit, MatchJournal, OutputEvents, ResolvedRuntimeLimits, RuntimeLimitSpec,
 };
 
-pub use error::RuntimeError;
+pub use error::{LanguageSymbol, RuntimeError};
 pub use execution_trace::{ExecutionTrace, TraceEvent, TraceNode, TraceRecord, TraceRecorder};
-pub use materializer::{ValueMaterializer, materialize_verified};
+pub use materializer::{JsonMaterializer, ValueMaterializer, materialize_verified};
 pub use result_provenance::{ProvenanceBinding, ResultProvenanceEntry, extract_result_provenance};
 pub use trace::{NoopTracer, PrintTracer, PrintTracerBuilder, Tracer, Verbosity};
 pub use value::{NodeValue, Value};
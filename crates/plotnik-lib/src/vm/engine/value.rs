@@ -163,6 +163,153 @@ impl Value<'_> {
         format_value(&mut ctx, self, 0);
         out
     }
+
+    /// Render as an indented tree for reading rather than parsing.
+    ///
+    /// Record fields print as `name: value` lines, list items as `- value`
+    /// bullets, nodes as `kind@start..end "text"`, and variants as their case
+    /// name followed by the payload. A non-empty record or list under a label
+    /// opens a block indented two more columns. Every line starts at least
+    /// `indent` columns in; there is no trailing newline.
+    pub fn render_tree(&self, indent: usize) -> String {
+        let mut out = String::new();
+        let mut stack = vec![TreeItem {
+            label: TreeLabel::Root,
+            value: self,
+            indent,
+        }];
+        while let Some(item) = stack.pop() {
+            emit_tree_item(&mut out, item, &mut stack);
+        }
+        out
+    }
+}
+
+/// How a tree line introduces its value.
+#[derive(Clone, Copy)]
+enum TreeLabel<'a> {
+    /// Top-level value, or a variant's block payload: no prefix, and a record or
+    /// list lays its children out in place.
+    Root,
+    /// `name: `
+    Field(&'a str),
+    /// `- `
+    Bullet,
+}
+
+/// One pending value for the iterative tree printer.
+struct TreeItem<'a> {
+    label: TreeLabel<'a>,
+    value: &'a Value<'a>,
+    indent: usize,
+}
+
+/// Write one labelled value, deferring block children onto `stack`.
+///
+/// Like [`format_value`], nesting is driven by an explicit stack: output depth
+/// tracks source depth for captured-recursive queries.
+fn emit_tree_item<'a>(out: &mut String, item: TreeItem<'a>, stack: &mut Vec<TreeItem<'a>>) {
+    let TreeItem {
+        label,
+        value,
+        indent,
+    } = item;
+    match value {
+        Value::Record(fields) if !fields.is_empty() => {
+            let indent = open_tree_block(out, label, indent);
+            stack.extend(fields.iter().rev().map(|(key, value)| TreeItem {
+                label: TreeLabel::Field(key),
+                value,
+                indent,
+            }));
+        }
+        Value::List(items) if !items.is_empty() => {
+            let indent = open_tree_block(out, label, indent);
+            stack.extend(items.iter().rev().map(|value| TreeItem {
+                label: TreeLabel::Bullet,
+                value,
+                indent,
+            }));
+        }
+        _ => {
+            start_tree_line(out, label, indent);
+            // Variant chains stay on one line; the first block payload drops below.
+            let mut value = value;
+            while let Value::Variant { case, payload } = value {
+                out.push_str(case);
+                let Some(payload) = payload.as_deref() else {
+                    return;
+                };
+                if is_tree_block(payload) {
+                    stack.push(TreeItem {
+                        label: TreeLabel::Root,
+                        value: payload,
+                        indent: indent + 2,
+                    });
+                    return;
+                }
+                out.push(' ');
+                value = payload;
+            }
+            push_tree_leaf(out, value);
+        }
+    }
+}
+
+fn is_tree_block(value: &Value<'_>) -> bool {
+    match value {
+        Value::Record(fields) => !fields.is_empty(),
+        Value::List(items) => !items.is_empty(),
+        _ => false,
+    }
+}
+
+/// Start a line for `label`'s value, written inline after the prefix.
+fn start_tree_line(out: &mut String, label: TreeLabel<'_>, indent: usize) {
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    push_indent(out, indent);
+    match label {
+        TreeLabel::Root => {}
+        TreeLabel::Field(name) => {
+            out.push_str(name);
+            out.push_str(": ");
+        }
+        TreeLabel::Bullet => out.push_str("- "),
+    }
+}
+
+/// Write the header line of a block under `label` and return its children's
+/// indent. The root writes no header, so its children stay in place.
+fn open_tree_block(out: &mut String, label: TreeLabel<'_>, indent: usize) -> usize {
+    if let TreeLabel::Root = label {
+        return indent;
+    }
+    start_tree_line(out, label, indent);
+    // Drop the separator space `start_tree_line` left after the prefix.
+    out.pop();
+    indent + 2
+}
+
+fn push_tree_leaf(out: &mut String, value: &Value<'_>) {
+    match value {
+        Value::Absent => out.push_str("null"),
+        Value::Node(node) => {
+            let _ = write!(out, "{}@{}..{} \"", node.kind, node.span.0, node.span.1);
+            escape_json_into(out, node.text);
+            out.push('"');
+        }
+        Value::Text(text) => {
+            out.push('"');
+            escape_json_into(out, text);
+            out.push('"');
+        }
+        Value::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+        Value::List(_) => out.push_str("[]"),
+        Value::Record(_) => out.push_str("{}"),
+        Value::Variant { .. } => unreachable!("variants render in `emit_tree_item`"),
+    }
 }
 
 /// `indent` varies by recursion depth; the rest is shared state threaded through every call.
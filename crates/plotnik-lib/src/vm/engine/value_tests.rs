@@ -0,0 +1,59 @@
+use super::{NodeValue, Value};
+
+fn node(kind: &'static str, text: &'static str, start: u32) -> Value<'static> {
+    Value::Node(NodeValue {
+        kind,
+        text,
+        span: (start, start + text.len() as u32),
+    })
+}
+
+#[test]
+fn render_tree_nests_records_lists_and_nodes() {
+    let value = Value::Record(vec![(
+        "decl",
+        Value::Record(vec![
+            ("name", node("identifier", "foo", 4)),
+            (
+                "args",
+                Value::List(vec![
+                    node("number", "1", 8),
+                    node("string", "\"hi\"", 11),
+                    Value::Variant {
+                        case: "Spread",
+                        payload: Some(Box::new(Value::Record(vec![(
+                            "arg",
+                            node("identifier", "rest", 20),
+                        )]))),
+                    },
+                ]),
+            ),
+            ("exported", Value::Bool(false)),
+            ("doc", Value::Absent),
+            ("tags", Value::List(Vec::new())),
+        ]),
+    )]);
+
+    insta::assert_snapshot!(value.render_tree(0), @r#"
+    decl:
+      name: identifier@4..7 "foo"
+      args:
+        - number@8..9 "1"
+        - string@11..15 "\"hi\""
+        - Spread
+          arg: identifier@20..24 "rest"
+      exported: false
+      doc: null
+      tags: []
+    "#);
+}
+
+#[test]
+fn render_tree_keeps_leaf_payloads_inline() {
+    let value = Value::Variant {
+        case: "Num",
+        payload: Some(Box::new(Value::Text("42"))),
+    };
+
+    assert_eq!(value.render_tree(2), "  Num \"42\"");
+}
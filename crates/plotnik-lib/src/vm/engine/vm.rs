@@ -1,6 +1,8 @@
 //! Virtual machine for executing compiled Plotnik queries.
 
-use tree_sitter::Tree;
+use std::time::Instant;
+
+use tree_sitter::{Node, Tree};
 
 use crate::bytecode::{
     CodeAddr, DecodedCall, DecodedInstr, DecodedMatch, DecodedPredicate, Effect, EffectKind,
@@ -14,7 +16,7 @@ use plotnik_runtime::{
     Resume, RuntimeLimitSpec, SkipPolicy,
 };
 
-use super::error::{ControlFlow, RuntimeError, Signal};
+use super::error::{ControlFlow, LanguageSymbol, RuntimeError, Signal};
 use super::trace::{NoopTracer, Tracer};
 use super::value::node_text;
 
@@ -22,6 +24,10 @@ use super::value::node_text;
 /// sampled; must be a power of two minus one.
 const MEMORY_SAMPLE_MASK: u64 = 1024 - 1;
 
+/// Bitmask selecting the matcher dispatches on which the deadline is checked.
+/// Reading the clock costs far more than a dispatch, so it is sampled sparsely.
+const DEADLINE_SAMPLE_MASK: u64 = 4096 - 1;
+
 /// Resource usage observed during one VM run.
 #[derive(Debug, Clone, Copy, serde::Serialize)]
 pub struct RunStats {
@@ -46,15 +52,19 @@ pub struct VM<'t> {
 
     pub(crate) fuel_used: u64,
     pub(crate) limits: ResolvedRuntimeLimits,
+    pub(crate) deadline: Option<Instant>,
 
     pub(crate) source: &'t str,
+    pub(crate) tree: &'t Tree,
 }
 
 /// Builder for VM instances.
 pub struct VMBuilder<'t> {
     source: &'t str,
     tree: &'t Tree,
+    root: Option<Node<'t>>,
     spec: RuntimeLimitSpec,
+    deadline: Option<Instant>,
 }
 
 impl<'t> VMBuilder<'t> {
@@ -62,10 +72,22 @@ impl<'t> VMBuilder<'t> {
         Self {
             source,
             tree,
+            root: None,
             spec: RuntimeLimitSpec::default(),
+            deadline: None,
         }
     }
 
+    /// Run against the subtree rooted at `node` instead of the whole tree.
+    ///
+    /// The entry point matches `node` itself, exactly as it would the tree
+    /// root, and navigation never leaves the subtree: `node`'s siblings and
+    /// ancestors are out of reach. `node` must belong to the builder's tree.
+    pub fn root(mut self, node: Node<'t>) -> Self {
+        self.root = Some(node);
+        self
+    }
+
     /// Set the runtime limit policy. `Auto` limits are sized from the source
     /// tree's node count when [`Self::build`] resolves them.
     pub fn limits(mut self, spec: RuntimeLimitSpec) -> Self {
@@ -73,16 +95,30 @@ impl<'t> VMBuilder<'t> {
         self
     }
 
-    /// Build the VM, resolving `Auto` limits against the source's node count.
+    /// Stop the run with [`RuntimeError::Timeout`] once `deadline` has passed.
+    ///
+    /// Unlike fuel, a deadline is not deterministic: the clock is read every
+    /// few thousand dispatches, so a run may overshoot it by that much work.
+    /// Targets without a monotonic clock (`wasm32-unknown-unknown`) must not
+    /// set one.
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Build the VM, resolving `Auto` limits against the node count of the
+    /// tree (or of the subtree set with [`Self::root`]).
     pub fn build(self) -> VM<'t> {
-        let source_nodes =
-            u32::try_from(self.tree.root_node().descendant_count()).unwrap_or(u32::MAX);
+        let root = self.root.unwrap_or_else(|| self.tree.root_node());
+        let source_nodes = u32::try_from(root.descendant_count()).unwrap_or(u32::MAX);
         VM {
-            engine: Engine::new(self.tree.walk()),
+            engine: Engine::new(root.walk()),
             ip: CodeAddr::ZERO,
             fuel_used: 0,
             limits: self.spec.resolve(source_nodes),
+            deadline: self.deadline,
             source: self.source,
+            tree: self.tree,
         }
     }
 }
@@ -110,6 +146,53 @@ impl<'t> VM<'t> {
         self.execute_with(module, entry_point, &mut NoopTracer)
     }
 
+    /// Like [`Self::execute`], but first verify that the tree's language agrees
+    /// with the module's node-kind and field tables.
+    ///
+    /// Running a module against a tree from another grammar (or another
+    /// version of the same one) otherwise matches against whatever symbols
+    /// happen to share its ids. A closely related language whose shared ids
+    /// line up passes; the first id named differently fails with
+    /// [`RuntimeError::LanguageMismatch`] before any matching starts.
+    pub fn execute_checked(
+        self,
+        module: &Module,
+        entry_point: &EntryPoint,
+    ) -> Result<MatchJournal<'t>, RuntimeError> {
+        self.check_language(module)?;
+        self.execute(module, entry_point)
+    }
+
+    fn check_language(&self, module: &Module) -> Result<(), RuntimeError> {
+        let language = self.tree.language();
+        let strings = module.strings();
+        let mismatch = |symbol, id: u16, expected: &str, found: Option<&str>| {
+            Err(RuntimeError::LanguageMismatch {
+                symbol,
+                id,
+                expected: expected.to_string(),
+                found: found.map(str::to_string),
+            })
+        };
+        for entry in module.node_kinds().iter() {
+            let id = u16::from(entry.symbol());
+            let expected = strings.get(entry.name());
+            let found = language.node_kind_for_id(id);
+            if found != Some(expected) {
+                return mismatch(LanguageSymbol::NodeKind, id, expected, found);
+            }
+        }
+        for entry in module.node_fields().iter() {
+            let id = u16::from(entry.symbol());
+            let expected = strings.get(entry.name());
+            let found = language.field_name_for_id(id);
+            if found != Some(expected) {
+                return mismatch(LanguageSymbol::Field, id, expected, found);
+            }
+        }
+        Ok(())
+    }
+
     /// Execute query with a tracer for debugging.
     ///
     /// The tracer is generic, so `NoopTracer` calls are optimized away
@@ -171,6 +254,14 @@ impl<'t> VM<'t> {
                 }
             }
 
+            if self.fuel_used & DEADLINE_SAMPLE_MASK == 0
+                && let Some(deadline) = self.deadline
+                && Instant::now() >= deadline
+            {
+                let stats = self.finish_stats(&mut peak_live_heap_bytes);
+                return (Err(RuntimeError::Timeout), stats);
+            }
+
             // Fetch and dispatch. The IP must address a validated instruction
             // start; a violation localizes a bad jump to the address that wrote
             // `ip`, before decoding begins mid-instruction.
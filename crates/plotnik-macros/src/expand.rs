@@ -115,8 +115,10 @@ fn try_expand(input: TokenStream, anchors: &mut RebuildAnchors) -> Result<String
         let rendered = emission
             .diagnostics()
             .render_colored(compiled.source_map(), false);
-        let message = rendered.strip_prefix("error: ").unwrap_or(&rendered);
-        return Err(ExpandError::new(query_span, message));
+        return Err(ExpandError::new(
+            query_span,
+            strip_severity_heading(&rendered),
+        ));
     }
     emission
         .into_artifact()
@@ -136,15 +138,25 @@ fn compile_strict_query(
     let diagnostics = compiled.diagnostics();
     if diagnostics.has_errors() || diagnostics.has_warnings() {
         let rendered = diagnostics.render_colored(compiled.source_map(), false);
-        // The message lands under rustc's own `error:` heading; the first
-        // rendered severity tag would double it, so it hands that role over.
-        let message = rendered.strip_prefix("error: ").unwrap_or(&rendered);
-        return Err(ExpandError::new(span, message));
+        return Err(ExpandError::new(span, strip_severity_heading(&rendered)));
     }
 
     Ok(compiled)
 }
 
+/// The message lands under rustc's own `error:` heading; the first rendered
+/// severity tag (`error[PLTnnnn]: `) would double it, so it hands that role over.
+fn strip_severity_heading(rendered: &str) -> &str {
+    let Some(rest) = rendered.strip_prefix("error") else {
+        return rendered;
+    };
+    let rest = match rest.strip_prefix('[') {
+        Some(coded) => coded.split_once(']').map_or(rest, |(_, after)| after),
+        None => rest,
+    };
+    rest.strip_prefix(": ").unwrap_or(rendered)
+}
+
 /// Every definition becomes snake_case items (`{def}_trace`, the
 /// `parse`/`matches` surface). Distinct PascalCase names can collapse to one
 /// snake form (`HTTPServer` / `HttpServer`); generated code would then fail
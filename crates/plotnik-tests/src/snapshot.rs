@@ -69,6 +69,7 @@ pub fn parse_section_header(line: &str) -> Option<String> {
                 | "ast"
                 | "definitions"
                 | "nfa"
+                | "graph"
                 | "bytecode"
                 | "mapped"
                 | "typescript"
@@ -18,13 +18,16 @@
 //! | ------------ | ---------------------------------------------------------------------------- |
 //! | `02-parser`  | cst, ast                                                                     |
 //! | `03-analyze` | definitions                                                                  |
-//! | `04-emit/bytecode` | nfa, bytecode                                                         |
-//! | `04-emit/types` | typescript, rust types (serde impls under a `serde/` folder)             |
+//! | `04-emit/bytecode` | nfa, graph (under a `graph/` folder), bytecode                         |
+//! | `04-emit/types` | typescript, rust types (serde impls under a `serde/` folder, JSON Schema under `json_schema/`, Python under `python/`) |
 //! | `04-emit/rust/module` | generated Rust matcher module                                      |
 //! | `06-vm`      | typescript, output, inspection if enabled, bytecode, trace (requires input) |
 //!
 //! Compile-stage snapshots under an `inspection/` folder compile with
-//! `BytecodeConfig::inspection(BytecodeInspection::Spans)`.
+//! `BytecodeConfig::inspection(BytecodeInspection::Spans)`. Type snapshots under a
+//! `type_prefix/`, `type_suffix/`, `reserved_names/`, `readonly/`, or `validators/`
+//! folder render TypeScript with the matching option (see `typescript_config`);
+//! `validators/include_points/` also declares node points.
 //!
 //! The `DIAGNOSTICS` section renders whenever the query produces warnings or errors.
 //! Errors are terminal for the compile stages (bytecode/typescript/rust/trace/output
@@ -63,15 +66,17 @@ use tree_sitter::{Language as TsLanguage, Parser as TsParser, Tree};
 use plotnik_lib::bytecode::{Module, dump as dump_bytecode};
 use plotnik_lib::grammar::{Grammar, raw::RawGrammar};
 use plotnik_lib::{
-    BytecodeConfig, BytecodeInspection, Colors, CompiledQuery, PrintTracer, QueryBuilder,
-    RuntimeError, RustCodegenConfig, SourceMap, SourcePath, TraceRecorder, TypeScriptBinding,
-    TypeScriptCodegenConfig, VM, Verbosity, extract_result_provenance, materialize_verified,
+    BytecodeConfig, BytecodeInspection, Colors, CompiledQuery, JsonMaterializer, JsonSchemaConfig,
+    OutputEvents, PrintTracer, PythonCodegenConfig, QueryBuilder, RuntimeError, RustCodegenConfig,
+    SourceMap, SourcePath, TraceRecorder, TypeScriptBinding, TypeScriptCodegenConfig, VM, Value,
+    Verbosity, extract_result_provenance, materialize_verified,
 };
 use plotnik_tests::snapshot::parse_document;
 use support::formatter::Assessment;
 use support::snapshots::{
-    GeneratedOutput, GeneratedSection, InspectionPolicy, MappingPolicy, SectionKind, SerdePolicy,
-    Snapshot, SnapshotKind, SnapshotMode, TriviaPolicy, VmMode, snapshot,
+    GeneratedOutput, GeneratedSection, GraphPolicy, InspectionPolicy, MappingPolicy, PythonPolicy,
+    SchemaPolicy, SectionKind, SerdePolicy, Snapshot, SnapshotKind, SnapshotMode, TriviaPolicy,
+    TypeScriptPolicy, VmMode, snapshot,
 };
 
 mod support;
@@ -300,7 +305,9 @@ fn render_compile(
     });
 
     match kind {
-        SnapshotKind::Bytecode { inspection, .. } => {
+        SnapshotKind::Bytecode {
+            inspection, graph, ..
+        } => {
             let emission = emit_bytecode(&compiled, inspection);
             let module = emission
                 .artifact()
@@ -316,14 +323,27 @@ fn render_compile(
                 .dump_nfa(Colors::new(false))
                 .expect("valid query should compile to a module");
             out.push(GeneratedSection::new(SectionKind::Nfa, nfa));
+            if matches!(graph, GraphPolicy::Include) {
+                let graph = compiled
+                    .dump_nfa_dot()
+                    .expect("valid query should compile to a module");
+                out.push(GeneratedSection::new(SectionKind::Graph, graph));
+            }
             out.push(GeneratedSection::new(
                 SectionKind::Bytecode,
                 dump_bytecode(module, Colors::new(false)),
             ));
         }
-        SnapshotKind::Types { serde, mapping, .. } => {
+        SnapshotKind::Types {
+            serde,
+            mapping,
+            schema,
+            python,
+            typescript: policy,
+            ..
+        } => {
             out.extend(diag);
-            let typescript = render_typescript(&compiled);
+            let typescript = render_typescript(&compiled, policy);
             out.push(GeneratedSection::new(
                 SectionKind::TypeScript,
                 typescript.clone(),
@@ -338,7 +358,7 @@ fn render_compile(
             out.push(GeneratedSection::new(SectionKind::Rust, rust));
             if matches!(mapping, MappingPolicy::Include) {
                 let (mapped_types, ranges) = compiled
-                    .emit_types(typescript_config())
+                    .emit_types(typescript_config(policy))
                     .expect("TypeScript emission answers")
                     .into_artifact()
                     .expect("valid query emits TypeScript types")
@@ -352,6 +372,29 @@ fn render_compile(
                     render_mapped(&mapped_types, &ranges),
                 ));
             }
+            if matches!(schema, SchemaPolicy::Include) {
+                let json_schema = compiled
+                    .emit_types(JsonSchemaConfig::new())
+                    .expect("JSON Schema emission answers")
+                    .into_artifact()
+                    .expect("valid query emits a JSON Schema")
+                    .to_pretty_string();
+                out.push(GeneratedSection::new(SectionKind::JsonSchema, json_schema));
+            }
+            let python_config = match python {
+                PythonPolicy::TypedDicts => Some(PythonCodegenConfig::new()),
+                PythonPolicy::Dataclasses => Some(PythonCodegenConfig::new().dataclasses(true)),
+                PythonPolicy::Omit => None,
+            };
+            if let Some(python_config) = python_config {
+                let python = compiled
+                    .emit_types(python_config)
+                    .expect("Python emission answers")
+                    .into_artifact()
+                    .expect("valid query emits Python types")
+                    .into_source();
+                out.push(GeneratedSection::new(SectionKind::Python, python));
+            }
         }
         SnapshotKind::Matcher { .. } => {
             out.extend(diag);
@@ -388,7 +431,7 @@ fn render_compile(
             })?;
             out.push(GeneratedSection::new(
                 SectionKind::TypeScript,
-                render_typescript(&compiled),
+                render_typescript(&compiled, TypeScriptPolicy::Default),
             ));
             out.extend(diag);
             if emission.diagnostics().has_warnings() {
@@ -437,9 +480,9 @@ fn render_compile(
     Ok(out)
 }
 
-fn render_typescript(compiled: &CompiledQuery) -> String {
+fn render_typescript(compiled: &CompiledQuery, policy: TypeScriptPolicy) -> String {
     compiled
-        .emit_types(typescript_config())
+        .emit_types(typescript_config(policy))
         .expect("TypeScript emission answers")
         .into_artifact()
         .expect("valid query emits TypeScript types")
@@ -447,8 +490,17 @@ fn render_typescript(compiled: &CompiledQuery) -> String {
         .0
 }
 
-fn typescript_config() -> TypeScriptCodegenConfig {
-    TypeScriptCodegenConfig::new().emit_node_interface(false)
+fn typescript_config(policy: TypeScriptPolicy) -> TypeScriptCodegenConfig {
+    let config = TypeScriptCodegenConfig::new().emit_node_interface(false);
+    match policy {
+        TypeScriptPolicy::Default => config,
+        TypeScriptPolicy::TypePrefix => config.type_prefix("Q"),
+        TypeScriptPolicy::TypeSuffix => config.type_suffix("Result"),
+        TypeScriptPolicy::ReservedNames => config.reserved_names(["Expr", "Expr_1"]),
+        TypeScriptPolicy::Readonly => config.readonly(true),
+        TypeScriptPolicy::Validators => config.validators(true),
+        TypeScriptPolicy::ValidatorsWithPoints => config.validators(true).include_points(true),
+    }
 }
 
 fn emit_bytecode(
@@ -544,6 +596,7 @@ fn run_vm(scenario: VmScenario<'_>) -> Result<VmArtifacts, String> {
                     journal.output_events(),
                     Colors::new(false),
                 );
+                check_json_materializer(&scenario, journal.output_events(), &value);
                 plotnik_rt::debug::to_json(&value)
                     .expect("materialized result must serialize to debug JSON")
             }
@@ -589,6 +642,7 @@ fn run_vm(scenario: VmScenario<'_>) -> Result<VmArtifacts, String> {
                 journal.output_events(),
                 Colors::new(false),
             );
+            check_json_materializer(&scenario, journal.output_events(), &value);
             (
                 plotnik_rt::debug::to_json(&value)
                     .expect("materialized result must serialize to debug JSON"),
@@ -607,6 +661,16 @@ fn run_vm(scenario: VmScenario<'_>) -> Result<VmArtifacts, String> {
     })
 }
 
+/// Materializing straight to JSON must agree with serializing the native value.
+fn check_json_materializer(scenario: &VmScenario<'_>, events: OutputEvents<'_, '_>, value: &Value) {
+    let direct = JsonMaterializer::new(scenario.source, scenario.module).materialize(events);
+    let serialized = serde_json::to_value(value).expect("materialized result serializes to JSON");
+    assert_eq!(
+        direct, serialized,
+        "JSON materializer must match the serialized value"
+    );
+}
+
 struct Lang {
     grammar: &'static Grammar,
     ts: TsLanguage,
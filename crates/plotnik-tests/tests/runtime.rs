@@ -0,0 +1,440 @@
+//! Runtime boundaries that the snapshot corpus cannot exercise: native stack
+//! depth, deadlines, VM options a snapshot has no way to select, and host-side
+//! APIs over a run's journal and tree.
+
+use std::collections::BTreeMap;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use indoc::indoc;
+use plotnik_lib::bytecode::Module;
+use plotnik_lib::{
+    BytecodeConfig, Colors, Limit, NoopTracer, QueryBuilder, RuntimeError, RuntimeLimitSpec, VM,
+    Value, ValueMaterializer, materialize_verified, subtree_to_json,
+};
+use serde_json::json;
+use tree_sitter::{Node, Tree};
+
+mod support;
+
+/// Number of nested `unary_expression`s the query descends through. Each level
+/// leaves one call-retry checkpoint; the final failure unwinds all of them in a
+/// single `backtrack` call. Chosen far above any native-stack frame budget: a
+/// contiguous run of `DEPTH` recursive frames cannot fit in `STACK_SIZE` under any
+/// realistic frame size, so the pre-fix recursion aborts the binary here. It is
+/// not larger only because each checkpoint restore is O(tree-position) in
+/// tree-sitter's cursor, making a full unwind O(DEPTH²) — 10k keeps the test a few
+/// seconds while still being an order of magnitude past the stack's capacity.
+const DEPTH: usize = 10_000;
+
+/// Deliberately tiny: the pre-fix recursive `backtrack` would need ~`DEPTH` frames
+/// and overflow this long before the leaf. The iterative version uses O(1) native
+/// stack, so a constant handful of frames is all it needs.
+const STACK_SIZE: usize = 256 * 1024;
+
+/// A query whose match descends one `unary_expression` per level and then fails at
+/// the bottom, forcing one maximally deep contiguous backtrack:
+///
+/// - `Top` anchors at the program root and descends to the unary chain. The root
+///   preamble matches once (no tree-wide search), so the work stays linear in
+///   `DEPTH` rather than quadratic.
+/// - `Rec`'s escape alternative matches `number` — a real expression, so the pattern is
+///   matchable in principle (the grammar checker admits it) yet one that never
+///   appears in a chain of `!` operators over an identifier. Trying it fails and
+///   *consumes* the successor checkpoint at every level, so the descent leaves only
+///   call-retry checkpoints — a contiguous run that backtrack pops without
+///   re-entering. At the leaf `identifier`, both alternatives fail and the whole run
+///   unwinds at once.
+const QUERY: &str = indoc! {"
+    Rec = [Leaf: (number) Deep: (unary_expression (Rec))]
+    Top = (program (expression_statement (Rec)))
+"};
+
+fn compile(query: &str) -> Module {
+    let compiled = QueryBuilder::from_inline(query)
+        .compile(support::javascript_grammar())
+        .expect("query compiles");
+    assert!(
+        compiled.is_valid(),
+        "{}",
+        compiled.diagnostics().render(compiled.source_map())
+    );
+    compiled
+        .emit(BytecodeConfig::new())
+        .expect("bytecode emission answers")
+        .into_artifact()
+        .expect("query emits a module")
+}
+
+fn unbounded() -> RuntimeLimitSpec {
+    RuntimeLimitSpec {
+        fuel_limit: Limit::Unbounded,
+        memory: Limit::Unbounded,
+    }
+}
+
+/// Regression: deep VM backtracking must not overflow the native stack.
+///
+/// `VM::backtrack` once self-recursed in tail position over the checkpoint stack
+/// (vm.rs). The checkpoint stack's depth is set by the *source-tree shape* and is
+/// decoupled from call depth (the frame stack), so a single `backtrack` could
+/// unwind a run of call-retry checkpoints far deeper than the frame stack ever
+/// grew. Rust does not guarantee tail-call optimization, so on untrusted source
+/// that recursion aborted the process on the native stack.
+///
+/// The fix turned `backtrack` into a loop. This test runs the VM on a
+/// deliberately tiny (256 KiB) thread stack, so the pre-fix recursive version
+/// would abort the test binary here.
+#[test]
+fn deep_backtrack_does_not_overflow_native_stack() {
+    let module = compile(QUERY);
+
+    // A unary chain `!!!…!x` of DEPTH operators: DEPTH nested `unary_expression`s.
+    let source = format!("{}x", "!".repeat(DEPTH));
+    // Parse on the parent stack: tree-sitter parse/drop of a deep tree is its own
+    // concern; this test isolates the VM's backtracking.
+    let tree = support::parse_javascript(&source);
+
+    let entry = module.entry_point("Top").expect("Top is an entry point");
+
+    // Run the VM on a tiny stack so the pre-fix recursive `backtrack` would abort
+    // here. Both runtime limits are Unbounded so no resource ceiling cuts the run
+    // short before the deep unwind (the frame stack lives on the heap); the native
+    // stack is what's under test.
+    let outcome = thread::scope(|scope| {
+        let handle = thread::Builder::new()
+            .name("deep-backtrack".into())
+            .stack_size(STACK_SIZE)
+            .spawn_scoped(scope, || {
+                let vm = VM::builder(&source, &tree).limits(unbounded()).build();
+                let mut tracer = NoopTracer;
+                let result = vm.execute_with(&module, &entry, &mut tracer);
+                match result {
+                    Ok(_) => "matched",
+                    Err(RuntimeError::NoMatch) => "no-match",
+                    Err(RuntimeError::OutOfFuel(_)) => "fuel",
+                    Err(RuntimeError::MemoryLimitExceeded { .. }) => "memory",
+                    Err(RuntimeError::Timeout) => "timeout",
+                    Err(RuntimeError::CallFrame(_)) => "call-frame",
+                    Err(RuntimeError::LanguageMismatch { .. }) => "language",
+                }
+            })
+            .expect("spawn deep-backtrack thread");
+        handle.join().expect("deep-backtrack thread did not abort")
+    });
+
+    // The query cannot match (no statement_block bottoms out the chain), so the run
+    // unwinds fully to a clean no-match instead of crashing.
+    assert_eq!(outcome, "no-match", "expected a clean no-match outcome");
+}
+
+/// A passed deadline ends the same deep run with `Timeout`. The clock is only
+/// read every few thousand dispatches, so the run must last well past one
+/// sampling interval for the check to fire at all.
+#[test]
+fn passed_deadline_times_out() {
+    let module = compile(QUERY);
+    let source = format!("{}x", "!".repeat(DEPTH));
+    let tree = support::parse_javascript(&source);
+    let entry = module.entry_point("Top").expect("Top is an entry point");
+    let vm = VM::builder(&source, &tree)
+        .limits(unbounded())
+        .deadline(Instant::now())
+        .build();
+
+    let result = vm.execute(&module, &entry);
+
+    assert!(matches!(result, Err(RuntimeError::Timeout)));
+}
+
+#[test]
+fn distant_deadline_does_not_interrupt() {
+    let module = compile(QUERY);
+    let source = format!("{}x", "!".repeat(DEPTH));
+    let tree = support::parse_javascript(&source);
+    let entry = module.entry_point("Top").expect("Top is an entry point");
+    let vm = VM::builder(&source, &tree)
+        .limits(unbounded())
+        .deadline(Instant::now() + Duration::from_secs(3600))
+        .build();
+
+    let result = vm.execute(&module, &entry);
+
+    assert!(matches!(result, Err(RuntimeError::NoMatch)));
+}
+
+/// A captured-recursive query materializes output as deep as the match, so both
+/// rendering (`Value::format`) and dropping the value must avoid native recursion.
+/// Build a deeply nested value, then render and drop it on a tiny stack: the
+/// recursive printer and the derived recursive drop would each overflow here.
+#[test]
+fn deep_value_render_and_drop_do_not_overflow_native_stack() {
+    // Far past any native-stack frame budget. Construction is bottom-up and linear,
+    // and compact rendering is linear in depth, so a large depth stays cheap.
+    const VALUE_DEPTH: usize = 200_000;
+
+    let len = thread::scope(|scope| {
+        let handle = thread::Builder::new()
+            .name("deep-value".into())
+            .stack_size(STACK_SIZE)
+            .spawn_scoped(scope, || {
+                // Bottom-up so construction itself never recurses.
+                let mut value = Value::Absent;
+                for _ in 0..VALUE_DEPTH {
+                    value = Value::Record(vec![("inner", value)]);
+                }
+                let rendered = value.format(false, Colors::new(false));
+                // `value` drops here, on this same tiny stack.
+                rendered.len()
+            })
+            .expect("spawn deep-value thread");
+        handle.join().expect("deep-value thread did not abort")
+    });
+
+    // Each level contributes a fixed `{"inner":…}` wrapper, so output is linear in
+    // depth and non-trivial — confirming the whole chain was rendered.
+    assert!(
+        len > VALUE_DEPTH,
+        "expected rendered output longer than {VALUE_DEPTH}, saw {len}"
+    );
+}
+
+/// `Value::render_tree` walks the same deep values as `Value::format`, so it
+/// must avoid native recursion too.
+#[test]
+fn deep_value_tree_render_does_not_overflow_native_stack() {
+    // Indentation grows with depth, making output quadratic in it; this depth
+    // keeps the render a few tens of megabytes while still being far past the
+    // frame budget of `STACK_SIZE`.
+    const VALUE_DEPTH: usize = 5_000;
+
+    let lines = thread::scope(|scope| {
+        let handle = thread::Builder::new()
+            .name("deep-value-tree".into())
+            .stack_size(STACK_SIZE)
+            .spawn_scoped(scope, || {
+                let mut value = Value::Absent;
+                for _ in 0..VALUE_DEPTH {
+                    value = Value::Record(vec![("inner", value)]);
+                }
+                value.render_tree(0).lines().count()
+            })
+            .expect("spawn deep-value-tree thread");
+        handle.join().expect("deep-value-tree thread did not abort")
+    });
+
+    // One `inner:` header per level; the innermost line carries the leaf.
+    assert_eq!(lines, VALUE_DEPTH);
+}
+
+const CALL_QUERY: &str = "Q = (call_expression function: (identifier) @callee)\n";
+const CALLS: &str = "foo(1);\nbar(2);\n";
+
+/// The call inside the `index`-th statement.
+fn call(tree: &Tree, index: u32) -> Node<'_> {
+    tree.root_node()
+        .named_child(index)
+        .and_then(|statement| statement.named_child(0))
+        .expect("statement wraps a call")
+}
+
+/// Run `Q` rooted at `root` (the whole tree when `None`) and return the
+/// captured callee text.
+fn callee<'t>(
+    module: &Module,
+    tree: &'t Tree,
+    root: Option<Node<'t>>,
+) -> Result<String, RuntimeError> {
+    let entry = module.entry_point("Q").expect("Q is an entry point");
+    let mut builder = VM::builder(CALLS, tree);
+    if let Some(root) = root {
+        builder = builder.root(root);
+    }
+    let journal = builder.build().execute(module, &entry)?;
+    let value = materialize_verified(
+        CALLS,
+        module,
+        &entry,
+        journal.output_events(),
+        Colors::new(false),
+    );
+    let Value::Record(fields) = &value else {
+        panic!("Q yields a record, got {value:?}");
+    };
+    let Some((_, Value::Node(node))) = fields.iter().find(|(name, _)| *name == "callee") else {
+        panic!("Q captures a callee node, got {value:?}");
+    };
+    Ok(node.text.to_string())
+}
+
+/// The entry point matches the run's root exactly, so a query for a call fails
+/// against the whole program.
+#[test]
+fn whole_tree_run_matches_only_the_tree_root() {
+    let module = compile(CALL_QUERY);
+    let tree = support::parse_javascript(CALLS);
+
+    assert!(matches!(
+        callee(&module, &tree, None),
+        Err(RuntimeError::NoMatch)
+    ));
+    assert!(matches!(
+        callee(&module, &tree, Some(tree.root_node())),
+        Err(RuntimeError::NoMatch)
+    ));
+}
+
+#[test]
+fn rooted_run_matches_the_given_node() {
+    let module = compile(CALL_QUERY);
+    let tree = support::parse_javascript(CALLS);
+
+    assert_eq!(
+        callee(&module, &tree, Some(call(&tree, 0))).ok().as_deref(),
+        Some("foo")
+    );
+    assert_eq!(
+        callee(&module, &tree, Some(call(&tree, 1))).ok().as_deref(),
+        Some("bar")
+    );
+}
+
+#[test]
+fn rooted_run_stays_inside_the_subtree() {
+    let module = compile(CALL_QUERY);
+    let tree = support::parse_javascript(CALLS);
+    let argument = call(&tree, 0)
+        .child_by_field_name("arguments")
+        .expect("call has arguments");
+
+    assert!(matches!(
+        callee(&module, &tree, Some(argument)),
+        Err(RuntimeError::NoMatch)
+    ));
+}
+
+/// Captures carry a span, not the tree node; the subtree is recovered from the
+/// parsed tree and dumped with `subtree_to_json`.
+#[test]
+fn captured_call_serializes_its_subtree() {
+    let module = compile("Q = (call_expression) @call\n");
+    let tree = support::parse_javascript(CALLS);
+    let entry = module.entry_point("Q").expect("Q is an entry point");
+
+    let journal = VM::builder(CALLS, &tree)
+        .root(call(&tree, 0))
+        .build()
+        .execute(&module, &entry)
+        .expect("Q matches the call");
+    let value = materialize_verified(
+        CALLS,
+        &module,
+        &entry,
+        journal.output_events(),
+        Colors::new(false),
+    );
+    let Value::Record(fields) = &value else {
+        panic!("Q yields a record, got {value:?}");
+    };
+    let Some((_, Value::Node(captured))) = fields.iter().find(|(name, _)| *name == "call") else {
+        panic!("Q captures the call, got {value:?}");
+    };
+    let (start, end) = captured.span;
+    let node = tree
+        .root_node()
+        .named_descendant_for_byte_range(start as usize, end as usize)
+        .expect("captured span is a node in the tree");
+
+    assert_eq!(
+        subtree_to_json(node, false),
+        json!({
+            "kind": "call_expression",
+            "named": true,
+            "range": [0, 6],
+            "children": [
+                {
+                    "kind": "identifier",
+                    "named": true,
+                    "range": [0, 3],
+                    "field": "function",
+                    "children": [],
+                },
+                {
+                    "kind": "arguments",
+                    "named": true,
+                    "range": [3, 6],
+                    "field": "arguments",
+                    "children": [
+                        {
+                            "kind": "number",
+                            "named": true,
+                            "range": [4, 5],
+                            "children": [],
+                        },
+                    ],
+                },
+            ],
+        })
+    );
+}
+
+/// Hit counts are read straight from the committed journal, without building
+/// values; a capture on a skipped optional does not count.
+#[test]
+fn counts_each_present_capture() {
+    let module = compile(indoc! {r#"
+        Q = (program (expression_statement (call_expression
+              function: (identifier) @callee
+              arguments: (arguments (string)? @label)))* @calls)
+    "#});
+    let source = "foo(\"x\");\nbar(1);\nbaz(\"y\");\n";
+    let tree = support::parse_javascript(source);
+    let entry = module.entry_point("Q").expect("Q is an entry point");
+
+    let journal = VM::builder(source, &tree)
+        .build()
+        .execute(&module, &entry)
+        .expect("Q matches the program");
+    let hits = ValueMaterializer::new(source, &module).capture_hits(journal.output_events());
+
+    assert_eq!(
+        hits,
+        BTreeMap::from([("callee", 3), ("calls", 1), ("label", 2)])
+    );
+}
+
+#[test]
+fn checked_run_accepts_matching_language() {
+    let module = compile(CALL_QUERY);
+    let tree = support::parse_javascript(CALLS);
+    let entry = module.entry_point("Q").expect("Q is an entry point");
+
+    let result = VM::builder(CALLS, &tree)
+        .root(call(&tree, 0))
+        .build()
+        .execute_checked(&module, &entry);
+
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+/// `execute_checked` refuses a tree whose language disagrees with the module's
+/// symbol tables instead of matching against renumbered ids.
+#[test]
+fn checked_run_rejects_other_language() {
+    let module = compile(CALL_QUERY);
+    let tree = support::parse_typescript(CALLS);
+    let entry = module.entry_point("Q").expect("Q is an entry point");
+
+    let result = VM::builder(CALLS, &tree)
+        .root(call(&tree, 0))
+        .build()
+        .execute_checked(&module, &entry);
+
+    let Err(RuntimeError::LanguageMismatch {
+        expected, found, ..
+    }) = result
+    else {
+        panic!("expected a language mismatch, got {:?}", result.map(|_| ()));
+    };
+    assert_ne!(found.as_deref(), Some(expected.as_str()));
+}
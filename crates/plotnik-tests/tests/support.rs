@@ -24,9 +24,26 @@ pub fn javascript_grammar() -> &'static Grammar {
     &GRAMMAR
 }
 
+pub fn typescript_grammar() -> &'static Grammar {
+    static GRAMMAR: LazyLock<Grammar> = LazyLock::new(|| {
+        let raw = RawGrammar::from_json(load_arborium_grammar_json("arborium-typescript"))
+            .expect("typescript grammar fixture");
+        Grammar::from_raw(&raw).expect("typescript grammar metadata")
+    });
+
+    &GRAMMAR
+}
+
 pub fn parse_javascript(source: &str) -> Tree {
     let mut parser = TsParser::new();
     let lang: TsLanguage = arborium_javascript::language().into();
     parser.set_language(&lang).expect("set javascript language");
     parser.parse(source, None).expect("parse javascript source")
 }
+
+pub fn parse_typescript(source: &str) -> Tree {
+    let mut parser = TsParser::new();
+    let lang: TsLanguage = arborium_typescript::language().into();
+    parser.set_language(&lang).expect("set typescript language");
+    parser.parse(source, None).expect("parse typescript source")
+}
@@ -77,6 +77,37 @@ pub(crate) enum MappingPolicy {
     Omit,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum SchemaPolicy {
+    Include,
+    Omit,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PythonPolicy {
+    TypedDicts,
+    Dataclasses,
+    Omit,
+}
+
+/// TypeScript emission options a `04-emit/types` folder selects by name.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum TypeScriptPolicy {
+    Default,
+    TypePrefix,
+    TypeSuffix,
+    ReservedNames,
+    Readonly,
+    Validators,
+    ValidatorsWithPoints,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum GraphPolicy {
+    Include,
+    Omit,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum VmMode {
     StructuredTrace,
@@ -91,11 +122,15 @@ pub(crate) enum SnapshotKind {
     Analyze,
     Bytecode {
         inspection: InspectionPolicy,
+        graph: GraphPolicy,
         lints: LintPolicy,
     },
     Types {
         serde: SerdePolicy,
         mapping: MappingPolicy,
+        schema: SchemaPolicy,
+        python: PythonPolicy,
+        typescript: TypeScriptPolicy,
         lints: LintPolicy,
     },
     Matcher {
@@ -129,6 +164,11 @@ impl SnapshotKind {
                 } else {
                     InspectionPolicy::Omit
                 },
+                graph: if name.contains("graph") {
+                    GraphPolicy::Include
+                } else {
+                    GraphPolicy::Omit
+                },
                 lints,
             }),
             "04" if name.contains("types") => Ok(Self::Types {
@@ -142,6 +182,33 @@ impl SnapshotKind {
                 } else {
                     MappingPolicy::Omit
                 },
+                schema: if name.contains("json_schema") {
+                    SchemaPolicy::Include
+                } else {
+                    SchemaPolicy::Omit
+                },
+                python: if name.contains_path(&["python", "dataclasses"]) {
+                    PythonPolicy::Dataclasses
+                } else if name.contains("python") {
+                    PythonPolicy::TypedDicts
+                } else {
+                    PythonPolicy::Omit
+                },
+                typescript: if name.contains("type_prefix") {
+                    TypeScriptPolicy::TypePrefix
+                } else if name.contains("type_suffix") {
+                    TypeScriptPolicy::TypeSuffix
+                } else if name.contains("reserved_names") {
+                    TypeScriptPolicy::ReservedNames
+                } else if name.contains("readonly") {
+                    TypeScriptPolicy::Readonly
+                } else if name.contains_path(&["validators", "include_points"]) {
+                    TypeScriptPolicy::ValidatorsWithPoints
+                } else if name.contains("validators") {
+                    TypeScriptPolicy::Validators
+                } else {
+                    TypeScriptPolicy::Default
+                },
                 lints,
             }),
             "04" if name.contains_path(&["rust", "module"]) => Ok(Self::Matcher { lints }),
@@ -195,6 +262,7 @@ impl SnapshotKind {
             Self::Bytecode { .. } => &[
                 SectionKind::Diagnostics,
                 SectionKind::Nfa,
+                SectionKind::Graph,
                 SectionKind::Bytecode,
             ],
             Self::Types { .. } => &[
@@ -202,6 +270,8 @@ impl SnapshotKind {
                 SectionKind::TypeScript,
                 SectionKind::Rust,
                 SectionKind::Mapped,
+                SectionKind::JsonSchema,
+                SectionKind::Python,
             ],
             Self::Matcher { .. } => &[SectionKind::Diagnostics, SectionKind::Matcher],
             Self::Vm { .. } => &[
@@ -240,10 +310,13 @@ pub(crate) enum SectionKind {
     Ast,
     Definitions,
     Nfa,
+    Graph,
     Bytecode,
     TypeScript,
     Rust,
     Mapped,
+    JsonSchema,
+    Python,
     Matcher,
     Output,
     Inspection,
@@ -259,10 +332,13 @@ impl SectionKind {
             Self::Ast => "ast",
             Self::Definitions => "definitions",
             Self::Nfa => "nfa",
+            Self::Graph => "graph",
             Self::Bytecode => "bytecode",
             Self::TypeScript => "typescript",
             Self::Rust => "rust",
             Self::Mapped => "mapped",
+            Self::JsonSchema => "json_schema",
+            Self::Python => "python",
             Self::Matcher => "matcher",
             Self::Output => "output",
             Self::Inspection => "inspection",
@@ -285,10 +361,13 @@ const ALL_SECTION_KINDS: &[SectionKind] = &[
     SectionKind::Ast,
     SectionKind::Definitions,
     SectionKind::Nfa,
+    SectionKind::Graph,
     SectionKind::Bytecode,
     SectionKind::TypeScript,
     SectionKind::Rust,
     SectionKind::Mapped,
+    SectionKind::JsonSchema,
+    SectionKind::Python,
     SectionKind::Matcher,
     SectionKind::Output,
     SectionKind::Inspection,
@@ -215,9 +215,9 @@ fn resolve_entry_point(
 ) -> Result<EntryPoint, String> {
     let selected = match requested {
         Some(name) => name.to_string(),
-        None => entry_points
-            .last()
-            .cloned()
+        None => module
+            .default_entry_point_name()
+            .map(str::to_string)
             .ok_or_else(|| "bytecode module exports no entry points".to_string())?,
     };
 
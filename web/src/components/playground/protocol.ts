@@ -35,7 +35,10 @@ export interface WireSpan {
 }
 
 export interface WireDiagnostic {
+  /** Stable `PLTnnnn` identifier. */
   code: string;
+  /** snake_case diagnostic kind name. */
+  kind: string;
   severity: "Error" | "Warning" | string;
   message: string;
   span: WireSpan;